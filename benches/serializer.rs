@@ -0,0 +1,73 @@
+//!Compares `Serializer` with writing record field by field via `write_value`.
+//!
+//!Run with `cargo bench --bench serializer`
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use baffa::{StaticBuffer, WriteBufExt};
+use baffa::codec::Serializer;
+
+#[derive(Clone, Copy)]
+struct Record {
+    id: u32,
+    kind: u8,
+    flags: u16,
+    value: u64,
+    tag: [u8; 3],
+}
+
+const RECORD_SIZE: usize = 4 + 1 + 2 + 8 + 3;
+const COUNT: usize = 256;
+const ROUNDS: usize = 20_000;
+
+type Storage = StaticBuffer<[u8; COUNT * RECORD_SIZE]>;
+
+fn serializer(buffer: &mut Storage, record: &Record) {
+    let mut ser = Serializer::new(buffer, RECORD_SIZE).expect("to fit");
+    unsafe {
+        ser.value(&record.id).value(&record.kind).value(&record.flags).value(&record.value).slice(&record.tag);
+    }
+    ser.finish();
+}
+
+fn write_value(buffer: &mut Storage, record: &Record) {
+    buffer.write_value(&record.id);
+    buffer.write_value(&record.kind);
+    buffer.write_value(&record.flags);
+    buffer.write_value(&record.value);
+    buffer.write_value(&record.tag);
+}
+
+fn run(name: &str, write: fn(&mut Storage, &Record)) -> Duration {
+    let mut buffer = Storage::new();
+    let record = Record {
+        id: 1,
+        kind: 2,
+        flags: 3,
+        value: 4,
+        tag: *b"tag",
+    };
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        buffer.consume_all();
+        for _ in 0..COUNT {
+            write(black_box(&mut buffer), black_box(&record));
+        }
+        black_box(buffer.as_slice());
+    }
+    let elapsed = start.elapsed();
+
+    println!("{}: {:.2} ns/record", name, elapsed.as_nanos() as f64 / (ROUNDS * COUNT) as f64);
+    elapsed
+}
+
+fn main() {
+    //warm up
+    run("warm up", write_value);
+
+    let slow = run("write_value", write_value);
+    let fast = run("Serializer", serializer);
+    println!("speedup: {:.2}x", slow.as_nanos() as f64 / fast.as_nanos() as f64);
+}
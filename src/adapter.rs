@@ -0,0 +1,261 @@
+//!Adapters over buffers
+
+use core::{cmp, ops, slice};
+use crate::{Buf, ReadBuf, WriteBuf};
+
+const FRAME_HEADER_SIZE: usize = 4;
+
+///Writer, which prefixes every message with its length as 4 byte big endian integer.
+pub struct Framed<W> {
+    inner: W,
+}
+
+impl<W: WriteBuf> Framed<W> {
+    #[inline]
+    ///Creates new instance
+    pub const fn new(inner: W) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+    #[inline]
+    ///Returns reference to underlying buffer.
+    pub const fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    #[inline]
+    ///Returns underlying buffer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    #[inline]
+    ///Returns maximum size of payload that can be written, excluding header.
+    pub fn remaining(&self) -> usize {
+        let remaining = self.inner.remaining().saturating_sub(FRAME_HEADER_SIZE);
+        cmp::min(remaining, u32::max_value() as usize)
+    }
+
+    ///Writes length header followed by `payload`, returning number of written bytes.
+    ///
+    ///If whole message cannot fit, does nothing, returning 0
+    pub fn write_message(&mut self, payload: &[u8]) -> usize {
+        if payload.len() > self.remaining() {
+            return 0;
+        }
+
+        let header = (payload.len() as u32).to_be_bytes();
+        self.inner.write_slice(&header) + self.inner.write_slice(payload)
+    }
+}
+
+///Reader, which mirrors every consumed byte into sink.
+///
+///Useful to capture exactly what was consumed by parser.
+///Bytes are mirrored only while sink has space.
+pub struct Tee<R, W> {
+    reader: R,
+    sink: W,
+}
+
+impl<R: ReadBuf, W: WriteBuf> Tee<R, W> {
+    #[inline]
+    ///Creates new instance
+    pub const fn new(reader: R, sink: W) -> Self {
+        Self {
+            reader,
+            sink,
+        }
+    }
+
+    #[inline]
+    ///Returns reference to sink.
+    pub const fn sink(&self) -> &W {
+        &self.sink
+    }
+
+    #[inline]
+    ///Returns underlying reader and sink.
+    pub fn into_inner(self) -> (R, W) {
+        (self.reader, self.sink)
+    }
+}
+
+impl<R: ReadBuf, W> ops::Index<usize> for Tee<R, W> {
+    type Output = u8;
+
+    #[inline(always)]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.reader[index]
+    }
+}
+
+impl<R: ReadBuf, W> ops::IndexMut<usize> for Tee<R, W> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.reader[index]
+    }
+}
+
+impl<R: ReadBuf, W: WriteBuf> Buf for Tee<R, W> {
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.reader.capacity()
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.reader.len()
+    }
+
+    #[inline(always)]
+    fn get_range(&self, range: ops::Range<usize>) -> Option<&[u8]> {
+        self.reader.get_range(range)
+    }
+}
+
+impl<R: ReadBuf, W: WriteBuf> ReadBuf for Tee<R, W> {
+    #[inline(always)]
+    fn available(&self) -> usize {
+        self.reader.available()
+    }
+
+    #[inline(always)]
+    fn read_offset(&self) -> usize {
+        self.reader.read_offset()
+    }
+
+    unsafe fn consume(&mut self, step: usize) {
+        let mirror_len = cmp::min(step, self.sink.remaining());
+        for idx in 0..mirror_len {
+            let byte = self.reader[idx];
+            self.sink.write(&byte, 1);
+        }
+
+        self.reader.consume(step);
+    }
+
+    unsafe fn read(&mut self, ptr: *mut u8, size: usize) {
+        self.reader.read(ptr, size);
+        self.sink.write_slice(slice::from_raw_parts(ptr, size));
+    }
+}
+
+///Reader, which exposes at most `chunk` bytes at a time.
+///
+///Simulates fragmenting transport to test handling of partial reads.
+pub struct ChunkLimit<R> {
+    reader: R,
+    chunk: usize,
+}
+
+impl<R: ReadBuf> ChunkLimit<R> {
+    #[inline]
+    ///Creates new instance
+    pub const fn new(reader: R, chunk: usize) -> Self {
+        Self {
+            reader,
+            chunk,
+        }
+    }
+
+    #[inline]
+    ///Returns underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: ReadBuf> ops::Index<usize> for ChunkLimit<R> {
+    type Output = u8;
+
+    #[inline(always)]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.reader[index]
+    }
+}
+
+impl<R: ReadBuf> ops::IndexMut<usize> for ChunkLimit<R> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.reader[index]
+    }
+}
+
+impl<R: ReadBuf> Buf for ChunkLimit<R> {
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.reader.capacity()
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.reader.len()
+    }
+
+    #[inline(always)]
+    fn get_range(&self, range: ops::Range<usize>) -> Option<&[u8]> {
+        self.reader.get_range(range)
+    }
+}
+
+impl<R: ReadBuf> ReadBuf for ChunkLimit<R> {
+    #[inline(always)]
+    fn available(&self) -> usize {
+        cmp::min(self.reader.available(), self.chunk)
+    }
+
+    #[inline(always)]
+    fn read_offset(&self) -> usize {
+        self.reader.read_offset()
+    }
+
+    #[inline(always)]
+    unsafe fn consume(&mut self, step: usize) {
+        self.reader.consume(step);
+    }
+
+    #[inline(always)]
+    unsafe fn read(&mut self, ptr: *mut u8, size: usize) {
+        self.reader.read(ptr, size);
+    }
+}
+
+#[cfg(feature = "std")]
+///Writer, which fails instead of performing partial write.
+///
+///Its `std::io::Write::write` returns `ErrorKind::WriteZero` if buffer cannot take whole slice,
+///leaving buffer unchanged.
+pub struct StrictWrite<'a, W> {
+    inner: &'a mut W,
+}
+
+#[cfg(feature = "std")]
+impl<'a, W: WriteBuf> StrictWrite<'a, W> {
+    #[inline]
+    ///Creates new instance
+    pub fn new(inner: &'a mut W) -> Self {
+        Self {
+            inner,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, W: WriteBuf> std::io::Write for StrictWrite<'a, W> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if buf.len() > self.inner.remaining() {
+            return Err(std::io::ErrorKind::WriteZero.into());
+        }
+
+        Ok(self.inner.write_slice(buf))
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
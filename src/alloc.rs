@@ -1,9 +1,10 @@
 extern crate alloc;
 
-use crate::{Buf, ReadBuf, WriteBuf, ContBuf};
+use crate::{Buf, DynBuf, ReadBuf, PeekBuf, WriteBuf, ContBuf};
 
 use core::{slice, mem, ptr};
-use alloc::vec::Vec;
+pub use alloc::vec::Vec;
+pub use alloc::collections::TryReserveError;
 
 impl Buf for Vec<u8> {
     #[inline(always)]
@@ -15,6 +16,29 @@ impl Buf for Vec<u8> {
     fn len(&self) -> usize {
         Vec::len(self)
     }
+
+    #[inline(always)]
+    fn get_range(&self, range: core::ops::Range<usize>) -> Option<&[u8]> {
+        self.get(range)
+    }
+}
+
+impl DynBuf for Vec<u8> {
+    #[inline(always)]
+    fn reserve(&mut self, size: usize) {
+        Vec::reserve(self, size)
+    }
+
+    fn shrink(&mut self, size: usize) {
+        let capacity = Vec::capacity(self).saturating_sub(size);
+        self.truncate(capacity);
+        self.shrink_to(capacity);
+    }
+
+    #[inline(always)]
+    fn try_reserve(&mut self, size: usize) -> Result<(), TryReserveError> {
+        Vec::try_reserve(self, size)
+    }
 }
 
 impl ContBuf for Vec<u8> {
@@ -61,6 +85,8 @@ impl ReadBuf for Vec<u8> {
     }
 }
 
+impl PeekBuf for Vec<u8> {}
+
 impl WriteBuf for Vec<u8> {
     #[inline(always)]
     unsafe fn advance(&mut self, step: usize) {
@@ -73,4 +99,22 @@ impl WriteBuf for Vec<u8> {
         ptr::copy_nonoverlapping(ptr, self.as_ptr().offset(self.len() as isize) as *mut u8, size);
         self.advance(size);
     }
+
+    #[cfg(feature = "std")]
+    ///Reads directly into spare capacity, zeroing it beforehand, as `Vec` doesn't track its initialization.
+    fn read_from<R: std::io::Read>(&mut self, r: &mut R) -> std::io::Result<usize> {
+        let len = self.len();
+        let spare = self.capacity() - len;
+        let spare = unsafe {
+            let ptr = self.as_mut_ptr().offset(len as isize);
+            ptr::write_bytes(ptr, 0, spare);
+            slice::from_raw_parts_mut(ptr, spare)
+        };
+
+        let size = core::cmp::min(r.read(spare)?, spare.len());
+        unsafe {
+            self.advance(size);
+        }
+        Ok(size)
+    }
 }
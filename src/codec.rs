@@ -0,0 +1,327 @@
+//!Serialization helpers on top of buffers
+
+use core::{fmt, mem, ptr};
+use crate::{ReadBuf, WriteBuf, ContBuf, CapacityError};
+use crate::stack::Le;
+
+///Serializer that writes sequence of values with single capacity check.
+///
+///Total size is validated once on creation, after which writes go directly into reserved space,
+///without querying buffer's `remaining` and advancing its cursor each time.
+///Hence writes are unchecked and caller must not write more than reserved size.
+///
+///Written bytes become part of buffer only after `finish`, dropping serializer discards them.
+pub struct Serializer<'a, W> {
+    buf: &'a mut W,
+    ptr: *mut u8,
+    size: usize,
+    written: usize,
+}
+
+impl<'a, W: WriteBuf + ContBuf> Serializer<'a, W> {
+    #[inline]
+    ///Creates new instance, reserving `size` bytes.
+    ///
+    ///Returns `None` if buffer has not enough space.
+    pub fn new(buf: &'a mut W, size: usize) -> Option<Self> {
+        if buf.remaining() < size {
+            return None;
+        }
+
+        let ptr = buf.as_write_slice().as_mut_ptr() as *mut u8;
+        Some(Self {
+            buf,
+            ptr,
+            size,
+            written: 0,
+        })
+    }
+
+    #[inline]
+    ///Returns number of reserved bytes that are not written yet.
+    pub const fn remaining(&self) -> usize {
+        self.size - self.written
+    }
+
+    #[inline]
+    ///Writes slice of bytes without checking reserved size.
+    ///
+    ///Caller must guarantee that slice fits `remaining`.
+    pub unsafe fn slice(&mut self, bytes: &[u8]) -> &mut Self {
+        debug_assert!(bytes.len() <= self.remaining(), "Serializer overflow");
+
+        ptr::copy_nonoverlapping(bytes.as_ptr(), self.ptr.offset(self.written as isize), bytes.len());
+        self.written += bytes.len();
+        self
+    }
+
+    #[inline]
+    ///Writes value by performing bit copy, without checking reserved size.
+    ///
+    ///Caller must guarantee that value fits `remaining`.
+    pub unsafe fn value<T: Copy + Sized>(&mut self, val: &T) -> &mut Self {
+        let size = mem::size_of::<T>();
+        debug_assert!(size <= self.remaining(), "Serializer overflow");
+
+        ptr::copy_nonoverlapping(val as *const _ as *const u8, self.ptr.offset(self.written as isize), size);
+        self.written += size;
+        self
+    }
+
+    #[inline]
+    ///Commits written bytes into buffer, returning their number.
+    pub fn finish(self) -> usize {
+        unsafe {
+            self.buf.advance(self.written);
+        }
+        self.written
+    }
+}
+
+///Describes type that can be encoded into buffer.
+pub trait Encode {
+    ///Encodes value into buffer, returning number of written bytes.
+    ///
+    ///If value cannot fit, should do nothing, returning 0
+    fn encode<W: WriteBuf>(&self, buf: &mut W) -> usize;
+}
+
+///Describes type that can be decoded from buffer.
+pub trait Decode: Sized {
+    ///Decodes value from buffer, consuming its bytes.
+    ///
+    ///If not enough bytes, should do nothing, returning `None`
+    fn decode<R: ReadBuf>(buf: &mut R) -> Option<Self>;
+}
+
+macro_rules! impl_codec {
+    ($($typ:ident),*) => {$(
+        impl Encode for $typ {
+            #[inline(always)]
+            fn encode<W: WriteBuf>(&self, buf: &mut W) -> usize {
+                Le(buf).write_value(self)
+            }
+        }
+
+        impl Decode for $typ {
+            #[inline]
+            fn decode<R: ReadBuf>(buf: &mut R) -> Option<Self> {
+                let mut val = mem::MaybeUninit::uninit();
+                match Le(buf).read_value(&mut val) {
+                    0 => None,
+                    _ => Some(unsafe { val.assume_init() }),
+                }
+            }
+        }
+    )*};
+}
+
+//Integers are encoded in little endian byte order.
+//Pointer sized integers are omitted as their size depends on host.
+impl_codec!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+///Fluent builder, appending fields to buffer.
+///
+///Once some field doesn't fit, builder fails and ignores further fields, while previously written
+///fields are left in buffer.
+pub struct Builder<'a, W> {
+    buf: &'a mut W,
+    written: usize,
+    failed: bool,
+}
+
+macro_rules! impl_builder_int {
+    ($($typ:ident => $le:ident, $be:ident;)*) => {$(
+        #[inline]
+        ///Appends integer in little endian byte order.
+        pub fn $le(&mut self, val: $typ) -> &mut Self {
+            self.bytes(&val.to_le_bytes())
+        }
+
+        #[inline]
+        ///Appends integer in big endian byte order.
+        pub fn $be(&mut self, val: $typ) -> &mut Self {
+            self.bytes(&val.to_be_bytes())
+        }
+    )*};
+}
+
+impl<'a, W: WriteBuf> Builder<'a, W> {
+    #[inline]
+    ///Creates new instance
+    pub fn new(buf: &'a mut W) -> Self {
+        Self {
+            buf,
+            written: 0,
+            failed: false,
+        }
+    }
+
+    #[inline]
+    ///Appends slice of bytes.
+    pub fn bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        if !self.failed {
+            if self.buf.remaining() < bytes.len() {
+                self.failed = true;
+            } else {
+                self.written += self.buf.write_slice(bytes);
+            }
+        }
+        self
+    }
+
+    #[inline]
+    ///Appends single byte.
+    pub fn u8(&mut self, val: u8) -> &mut Self {
+        self.bytes(&[val])
+    }
+
+    impl_builder_int!(
+        u16 => u16_le, u16_be;
+        u32 => u32_le, u32_be;
+        u64 => u64_le, u64_be;
+    );
+
+    #[inline]
+    ///Returns total number of written bytes, or error if some field didn't fit.
+    pub fn finish(&mut self) -> Result<usize, CapacityError> {
+        match self.failed {
+            true => Err(CapacityError),
+            false => Ok(self.written),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Error indicating that buffer has not enough bytes to parse.
+pub struct Incomplete;
+
+impl fmt::Display for Incomplete {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Not enough bytes")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Incomplete {
+}
+
+///Parser, reading fields from buffer.
+///
+///Bytes are consumed only on `finish`, hence if parsing fails at any field, buffer is left
+///unchanged.
+pub struct Parser<'a, R> {
+    buf: &'a mut R,
+    read: usize,
+}
+
+macro_rules! impl_parser_int {
+    ($($typ:ident => $le:ident, $be:ident;)*) => {$(
+        #[inline]
+        ///Reads integer in little endian byte order.
+        pub fn $le(&mut self) -> Result<$typ, Incomplete> {
+            self.array().map($typ::from_le_bytes)
+        }
+
+        #[inline]
+        ///Reads integer in big endian byte order.
+        pub fn $be(&mut self) -> Result<$typ, Incomplete> {
+            self.array().map($typ::from_be_bytes)
+        }
+    )*};
+}
+
+impl<'a, R: ReadBuf> Parser<'a, R> {
+    #[inline]
+    ///Creates new instance
+    pub fn new(buf: &'a mut R) -> Self {
+        Self {
+            buf,
+            read: 0,
+        }
+    }
+
+    #[inline]
+    ///Returns number of bytes that are not parsed yet.
+    pub fn remaining(&self) -> usize {
+        self.buf.available() - self.read
+    }
+
+    ///Reads `out.len()` bytes into `out`.
+    pub fn bytes(&mut self, out: &mut [u8]) -> Result<(), Incomplete> {
+        if self.remaining() < out.len() {
+            return Err(Incomplete);
+        }
+
+        for (idx, byte) in out.iter_mut().enumerate() {
+            *byte = self.buf[self.read + idx];
+        }
+        self.read += out.len();
+        Ok(())
+    }
+
+    #[inline]
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], Incomplete> {
+        let mut result = [0u8; N];
+        self.bytes(&mut result).map(|_| result)
+    }
+
+    #[inline]
+    ///Reads single byte.
+    pub fn u8(&mut self) -> Result<u8, Incomplete> {
+        self.array::<1>().map(|byte| byte[0])
+    }
+
+    impl_parser_int!(
+        u16 => u16_le, u16_be;
+        u32 => u32_le, u32_be;
+        u64 => u64_le, u64_be;
+    );
+
+    #[inline]
+    ///Consumes parsed bytes, returning their number.
+    pub fn finish(self) -> usize {
+        unsafe {
+            self.buf.consume(self.read);
+        }
+        self.read
+    }
+}
+
+#[inline]
+///Returns number of bytes written by `WriteBufExt::write_value` for type `T`.
+pub const fn encoded_len_value<T: Copy + Sized>() -> usize {
+    mem::size_of::<T>()
+}
+
+#[inline]
+///Returns number of bytes in LEB128 encoding of `value`, as read by `ReadBuf::read_lp_varint`.
+pub const fn varint_len(value: u64) -> usize {
+    let bits = 64 - (value | 1).leading_zeros() as usize;
+    bits.div_ceil(7)
+}
+
+#[inline]
+///Returns number of characters in hexadecimal encoding of `n` bytes, i.e. two per byte.
+pub const fn hex_len(n: usize) -> usize {
+    n * 2
+}
+
+///Returns number of bytes written by `WriteBuf::write_rle` for whole `data`.
+pub const fn rle_len(data: &[u8]) -> usize {
+    let mut len = 0;
+    let mut idx = 0;
+
+    while idx < data.len() {
+        let byte = data[idx];
+        let mut count = 0;
+        while idx < data.len() && data[idx] == byte && count < u8::max_value() {
+            idx += 1;
+            count += 1;
+        }
+        len += 2;
+    }
+
+    len
+}
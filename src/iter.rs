@@ -1,6 +1,8 @@
 //!Iterator over byte buffers
 
-use core::{iter};
+use core::{cmp, iter, mem};
+use crate::{ReadBuf, WriteBuf, CapacityError};
+use crate::stack::Buffer;
 
 #[derive(Clone, Copy)]
 ///Iterator over byte buffer.
@@ -150,3 +152,130 @@ impl<'a, T: crate::Buf> iter::DoubleEndedIterator for IterMut<'a, T> {
 
 impl<'a, T: crate::Buf> iter::FusedIterator for IterMut<'a, T> {
 }
+
+///Iterator over values, copied from readable bytes of buffer.
+///
+///Created by `ReadBufExt::values`.
+pub struct Values<'a, B, T> {
+    inner: &'a B,
+    cursor: usize,
+    value: core::marker::PhantomData<T>,
+}
+
+impl<'a, B: ReadBuf, T: Copy> Values<'a, B, T> {
+    #[inline]
+    pub(crate) fn new(inner: &'a B) -> Self {
+        Self {
+            inner,
+            cursor: 0,
+            value: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, B: ReadBuf, T: Copy> iter::Iterator for Values<'a, B, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let size = mem::size_of::<T>();
+        if size == 0 || self.inner.available() - self.cursor < size {
+            return None;
+        }
+
+        let mut value = mem::MaybeUninit::<T>::uninit();
+        let ptr = value.as_mut_ptr() as *mut u8;
+        for idx in 0..size {
+            unsafe {
+                ptr.offset(idx as isize).write(self.inner[self.cursor + idx]);
+            }
+        }
+        self.cursor += size;
+
+        Some(unsafe { value.assume_init() })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = match mem::size_of::<T>() {
+            0 => 0,
+            size => (self.inner.available() - self.cursor) / size,
+        };
+        (size, Some(size))
+    }
+}
+
+///Iterator over bits, consuming bytes of buffer.
+///
+///Created by `ReadBuf::read_bitmap`.
+pub struct Bits<'a, B> {
+    inner: &'a mut B,
+    remaining: usize,
+    byte: u8,
+    //Number of bits of `byte` already yielded
+    bit: u32,
+}
+
+impl<'a, B: ReadBuf> Bits<'a, B> {
+    #[inline]
+    pub(crate) fn new(inner: &'a mut B, bits: usize) -> Self {
+        Self {
+            inner,
+            remaining: bits,
+            byte: 0,
+            bit: 8,
+        }
+    }
+}
+
+impl<'a, B: ReadBuf> iter::Iterator for Bits<'a, B> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        if self.bit == 8 {
+            match self.inner.pop() {
+                Some(byte) => self.byte = byte,
+                None => {
+                    self.remaining = 0;
+                    return None;
+                }
+            }
+            self.bit = 0;
+        }
+
+        let result = self.byte & (0x80 >> self.bit) != 0;
+        self.bit += 1;
+        self.remaining -= 1;
+        Some(result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = (8 - self.bit as usize).saturating_add(self.inner.available().saturating_mul(8));
+        let size = cmp::min(self.remaining, size);
+        (size, Some(size))
+    }
+}
+
+///Extension to collect bytes into static buffer, failing on overflow instead of truncating.
+pub trait TryCollect: iter::Iterator<Item = u8> + Sized {
+    ///Collects all bytes into new `Buffer`.
+    ///
+    ///Returns `CapacityError` if iterator yields more bytes than buffer can fit.
+    fn try_collect_buffer<S: Sized>(self) -> Result<Buffer<S>, CapacityError> {
+        let mut buffer = Buffer::new();
+
+        for byte in self {
+            if buffer.write_slice(&[byte]) == 0 {
+                return Err(CapacityError);
+            }
+        }
+
+        Ok(buffer)
+    }
+}
+
+impl<I: iter::Iterator<Item = u8>> TryCollect for I {}
@@ -35,15 +35,22 @@
 
 #![no_std]
 #![warn(missing_docs)]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::style))]
+#![allow(clippy::ptr_offset_with_cast, clippy::missing_safety_doc, clippy::new_without_default, clippy::len_without_is_empty, clippy::wrong_self_convention, clippy::legacy_numeric_constants, clippy::manual_is_multiple_of)]
 
 #[cfg(feature = "std")]
 extern crate std;
 
-use core::{mem, cmp, ops};
+use core::{fmt, mem, cmp, ops, slice};
 
 pub mod stack;
 pub mod iter;
+pub mod rolling;
+pub mod codec;
+pub mod adapter;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod spsc;
+pub mod pool;
 #[cfg(feature = "alloc")]
 mod alloc;
 
@@ -52,6 +59,21 @@ pub type StaticBuffer<T> = stack::Buffer<T>;
 ///Alias to circular buffer.
 pub type RingBuffer<T> = stack::Ring<T>;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Error indicating that buffer has not enough capacity to perform operation.
+pub struct CapacityError;
+
+impl fmt::Display for CapacityError {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Not enough capacity")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {
+}
+
 ///Common buffer.
 pub trait Buf: ops::IndexMut<usize, Output=u8> + Sized {
     ///Returns size of the underlying memory in the buffer.
@@ -60,6 +82,142 @@ pub trait Buf: ops::IndexMut<usize, Output=u8> + Sized {
     ///Returns number of elements inside the buffer.
     fn len(&self) -> usize;
 
+    #[inline]
+    ///Returns `(len, remaining, capacity)` in a single call.
+    ///
+    ///Here `remaining` is always `capacity - len`, i.e. number of bytes that can be written before
+    ///buffer is full.
+    ///
+    ///Note that it is different from `WriteBuf::remaining` for circular buffers like `Ring`,
+    ///which always reports full capacity as free, because it overwrites oldest bytes instead of
+    ///failing.
+    fn stats(&self) -> (usize, usize, usize) {
+        let len = self.len();
+        let capacity = self.capacity();
+        (len, capacity - len, capacity)
+    }
+
+    ///Compares content of the buffer with `other` in constant time.
+    ///
+    ///Time taken doesn't depend on position of differing bytes, making it suitable to compare
+    ///secrets like MAC or tokens.
+    ///Length mismatch is accumulated into result together with bytes.
+    fn ct_eq(&self, other: &[u8]) -> bool {
+        let len = self.len();
+        let mut diff = len ^ other.len();
+
+        for idx in 0..cmp::min(len, other.len()) {
+            diff |= (self[idx] ^ other[idx]) as usize;
+        }
+
+        diff == 0
+    }
+
+    ///Compares content of the buffer with `other`, ignoring ASCII case.
+    fn eq_ignore_ascii_case(&self, other: &[u8]) -> bool {
+        self.len() == other.len() && other.iter().enumerate().all(|(idx, byte)| self[idx].eq_ignore_ascii_case(byte))
+    }
+
+    ///Returns XOR of all bytes in the buffer, as used by NMEA and other serial protocols.
+    fn xor_checksum(&self) -> u8 {
+        (0..self.len()).fold(0, |checksum, idx| checksum ^ self[idx])
+    }
+
+    #[inline]
+    ///Returns whether `xor_checksum` of the buffer is equal to `expected`.
+    fn verify_xor(&self, expected: u8) -> bool {
+        self.xor_checksum() == expected
+    }
+
+    ///Returns 16-bit one's complement sum of big endian words in the buffer, as used by Internet checksum.
+    ///
+    ///Odd last byte is padded with zero.
+    ///Checksum itself is bitwise NOT of the result.
+    fn ones_complement_sum(&self) -> u16 {
+        let len = self.len();
+        let mut sum = 0u64;
+
+        for idx in (0..len).step_by(2) {
+            let low = match idx + 1 < len {
+                true => self[idx + 1],
+                false => 0,
+            };
+            sum += u16::from_be_bytes([self[idx], low]) as u64;
+        }
+
+        while sum > u16::max_value() as u64 {
+            sum = (sum & u16::max_value() as u64) + (sum >> 16);
+        }
+
+        sum as u16
+    }
+
+    ///Returns number of zero bytes at the start of the buffer.
+    fn leading_zeros(&self) -> usize {
+        let len = self.len();
+        (0..len).position(|idx| self[idx] != 0).unwrap_or(len)
+    }
+
+    ///Returns number of zero bytes at the end of the buffer.
+    fn trailing_zeros(&self) -> usize {
+        let len = self.len();
+        (0..len).rev().position(|idx| self[idx] != 0).unwrap_or(len)
+    }
+
+    ///Returns number of differing bits between content of the buffer and `other`.
+    ///
+    ///Returns `None` if lengths differ.
+    fn hamming_distance(&self, other: &[u8]) -> Option<u32> {
+        if self.len() != other.len() {
+            return None;
+        }
+
+        Some(other.iter().enumerate().fold(0, |distance, (idx, byte)| distance + (self[idx] ^ byte).count_ones()))
+    }
+
+    ///XORs content of the buffer in place with `key`, cycling it, returning number of changed elements.
+    ///
+    ///Applying the same `key` twice restores original content.
+    ///If `key` is empty, does nothing, returning 0.
+    fn xor_with(&mut self, key: &[u8]) -> usize {
+        if key.is_empty() {
+            return 0;
+        }
+
+        let len = self.len();
+        for idx in 0..len {
+            self[idx] ^= key[idx % key.len()];
+        }
+
+        len
+    }
+
+    ///Returns elements within `range` as single slice, if it is within `len`.
+    ///
+    ///Circular buffers return `None` when `range` wraps around the end of storage.
+    fn get_range(&self, range: ops::Range<usize>) -> Option<&[u8]>;
+
+    ///Copies first `N` elements inside the buffer into array, in logical order.
+    ///
+    ///Returns `None` if buffer has less than `N` elements.
+    fn copy_to_array<const N: usize>(&self) -> Option<[u8; N]> {
+        if self.len() < N {
+            return None;
+        }
+
+        let mut result = [0u8; N];
+        for (idx, byte) in result.iter_mut().enumerate() {
+            *byte = self[idx];
+        }
+        Some(result)
+    }
+
+    #[cfg(feature = "alloc")]
+    ///Copies elements inside the buffer into newly allocated `Vec`, in logical order.
+    fn to_vec(&self) -> alloc::Vec<u8> {
+        self.iter().copied().collect()
+    }
+
     #[inline]
     ///Returns iterator over elements inside the buffer.
     fn iter(&self) -> iter::Iter<'_, Self> {
@@ -86,6 +244,16 @@ pub trait DynBuf {
     ///If `size` is bigger than `capacity` should behave as if `size` is equal (i.e. clear whole
     ///memory).
     fn shrink(&mut self, size: usize);
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    ///Reserves additional space, enough to at least fit `size`, returning error on allocation failure.
+    ///
+    ///Default implementation uses `reserve`, hence never fails.
+    fn try_reserve(&mut self, size: usize) -> Result<(), alloc::TryReserveError> {
+        self.reserve(size);
+        Ok(())
+    }
 }
 
 ///Describes buffer that uses single contiguous memory block
@@ -99,6 +267,58 @@ pub trait ContBuf {
 
     ///Returns slice of bytes that can be written (i.e. not written yet).
     fn as_write_slice(&mut self) -> &mut [mem::MaybeUninit<u8>];
+
+    #[inline]
+    ///Returns iterator over bytes that can be written.
+    ///
+    ///It doesn't move cursor, so after filling bytes, use `WriteBuf::advance` to mark them written.
+    fn iter_writable_mut(&mut self) -> slice::IterMut<'_, mem::MaybeUninit<u8>> {
+        self.as_write_slice().iter_mut()
+    }
+    #[inline]
+    ///Returns bytes that can be read as `str`, if they are valid UTF-8.
+    fn as_str(&self) -> Option<&str> {
+        core::str::from_utf8(self.as_read_slice()).ok()
+    }
+    #[inline]
+    ///Returns bytes before first `delim`, borrowing them from buffer.
+    ///
+    ///Borrowing counterpart of `ReadBuf::take_until`, that is not limited by size of output.
+    ///Bytes are not consumed, as consumption shifts content of contiguous buffers, hence once done
+    ///with result, consume `len() + 1` bytes to skip it together with delimiter.
+    fn peek_until(&self, delim: u8) -> Option<&[u8]> {
+        let bytes = self.as_read_slice();
+        let len = bytes.iter().position(|byte| *byte == delim)?;
+        Some(&bytes[..len])
+    }
+
+    ///Returns bytes that can be read as slice of `T`.
+    ///
+    ///Returns `None` if bytes are not aligned for `T` or their number is not multiple of its size.
+    ///User must guarantee that any bit pattern is valid for `T`.
+    unsafe fn as_typed_slice<T: Copy>(&self) -> Option<&[T]> {
+        let bytes = self.as_read_slice();
+        let size = mem::size_of::<T>();
+        if size == 0 || bytes.len() % size != 0 || bytes.as_ptr() as usize % mem::align_of::<T>() != 0 {
+            return None;
+        }
+
+        Some(slice::from_raw_parts(bytes.as_ptr() as *const T, bytes.len() / size))
+    }
+
+    ///Returns bytes that can be read as mutable slice of `T`.
+    ///
+    ///Returns `None` if bytes are not aligned for `T` or their number is not multiple of its size.
+    ///User must guarantee that any bit pattern is valid for `T`.
+    unsafe fn as_typed_slice_mut<T: Copy>(&mut self) -> Option<&mut [T]> {
+        let bytes = self.as_read_slice_mut();
+        let size = mem::size_of::<T>();
+        if size == 0 || bytes.len() % size != 0 || bytes.as_ptr() as usize % mem::align_of::<T>() != 0 {
+            return None;
+        }
+
+        Some(slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut T, bytes.len() / size))
+    }
 }
 
 ///Describes read-able buffer
@@ -111,6 +331,25 @@ pub trait ReadBuf: Buf {
         Buf::len(self)
     }
 
+    #[inline(always)]
+    ///Returns logical offset of read cursor.
+    ///
+    ///Returns 0 by default, which is the case for buffers that always read from the beginning.
+    fn read_offset(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    ///Returns byte at offset `n` from read cursor, without consuming it.
+    ///
+    ///Returns `None` if `n` is not less than `available()`.
+    fn peek_at(&self, n: usize) -> Option<u8> {
+        match n < self.available() {
+            true => Some(self[n]),
+            false => None,
+        }
+    }
+
     ///Moves cursor, considering bytes as consumed.
     unsafe fn consume(&mut self, step: usize);
 
@@ -122,6 +361,43 @@ pub trait ReadBuf: Buf {
     ///- `ptr` is always non-null.
     unsafe fn read(&mut self, ptr: *mut u8, size: usize);
 
+    #[cfg(feature = "std")]
+    ///Writes available bytes into `w`, consuming bytes it accepts, returning their number.
+    ///
+    ///Stops once `w` accepts nothing. Retries on `ErrorKind::Interrupted`, while any other error
+    ///is returned only if nothing is written yet, as written bytes are already consumed.
+    ///Default implementation writes contiguous bytes, as returned by `get_range`, with single call
+    ///per span, otherwise copies bytes in chunks through stack memory.
+    fn write_to<W: std::io::Write>(&mut self, w: &mut W) -> std::io::Result<usize> {
+        let mut chunk = [0u8; 64];
+        let mut written = 0;
+
+        while self.available() > 0 {
+            let available = self.available();
+            let accepted = match self.get_range(0..available) {
+                Some(bytes) => write_step(w, bytes, written)?,
+                None => {
+                    let chunk = &mut chunk[..cmp::min(available, 64)];
+                    for (idx, byte) in chunk.iter_mut().enumerate() {
+                        *byte = self[idx];
+                    }
+                    write_step(w, chunk, written)?
+                }
+            };
+
+            if accepted == 0 {
+                break;
+            }
+
+            unsafe {
+                self.consume(accepted);
+            }
+            written += accepted;
+        }
+
+        Ok(written)
+    }
+
     #[inline]
     ///Reads available bytes into slice
     fn read_slice(&mut self, bytes: &mut [u8]) -> usize {
@@ -129,16 +405,336 @@ pub trait ReadBuf: Buf {
 
         if read_len > 0 {
             unsafe {
-                self.read(bytes.as_mut_ptr(), bytes.len())
+                self.read(bytes.as_mut_ptr(), read_len)
             }
         }
 
         read_len
     }
+
+    #[inline]
+    ///Reads single byte, if available.
+    fn pop(&mut self) -> Option<u8> {
+        let mut byte = [0u8];
+        match self.read_slice(&mut byte) {
+            0 => None,
+            _ => Some(byte[0]),
+        }
+    }
+
+    #[inline]
+    ///Returns iterator over up to `bits` bits, most significant bit first, as written by `WriteBuf::write_bitmap`.
+    ///
+    ///Each byte is consumed once iterator reaches its first bit, discarding its unread bits.
+    fn read_bitmap(&mut self, bits: usize) -> iter::Bits<'_, Self> {
+        iter::Bits::new(self, bits)
+    }
+
+    ///Parses leading ASCII digits as decimal unsigned integer, consuming them.
+    ///
+    ///Parsing stops at first non-digit byte, which is not consumed.
+    ///Returns `None` without consuming anything if there is no leading digit or value overflows.
+    fn read_uint(&mut self) -> Option<u64> {
+        let available = self.available();
+        let mut value = 0u64;
+        let mut len = 0;
+
+        while len < available {
+            let byte = self[len];
+            if !byte.is_ascii_digit() {
+                break;
+            }
+
+            value = value.checked_mul(10)?.checked_add((byte - b'0') as u64)?;
+            len += 1;
+        }
+
+        if len == 0 {
+            return None;
+        }
+
+        unsafe {
+            self.consume(len);
+        }
+        Some(value)
+    }
+
+    ///Consumes bytes until `read_offset` is multiple of `alignment`, returning number of skipped bytes.
+    ///
+    ///`alignment` must be power of two.
+    ///
+    ///If not enough bytes, does nothing, returning 0.
+    ///Note that buffers with destructive reads, like `Buffer`, always read from the beginning,
+    ///hence their read offset is always aligned and nothing is skipped.
+    fn align_skip(&mut self, alignment: usize) -> usize {
+        debug_assert!(alignment.is_power_of_two(), "Alignment is not power of 2");
+        let skip = self.read_offset().wrapping_neg() & (alignment - 1);
+
+        if skip == 0 || self.available() < skip {
+            return 0;
+        }
+
+        unsafe {
+            self.consume(skip);
+        }
+        skip
+    }
+
+    ///Reads `n` bytes into `out`, consuming them only if they are valid UTF-8.
+    ///
+    ///Returns `None` without consuming anything if there are not enough bytes, they don't fit
+    ///`out` or they are not valid UTF-8.
+    fn read_str<'a>(&mut self, out: &'a mut [u8], n: usize) -> Option<&'a str> {
+        if n > self.available() || n > out.len() {
+            return None;
+        }
+
+        let out = &mut out[..n];
+        for (idx, byte) in out.iter_mut().enumerate() {
+            *byte = self[idx];
+        }
+
+        let text = core::str::from_utf8(out).ok()?;
+        unsafe {
+            self.consume(n);
+        }
+        Some(text)
+    }
+
+    ///Copies bytes before first `delim` into `out`, consuming them together with delimiter.
+    ///
+    ///Returns `None` without consuming anything if there is no `delim` or bytes don't fit `out`.
+    ///Result is copied, as consumption may shift or overwrite bytes within buffer.
+    ///Contiguous buffers can use `ContBuf::peek_until` to borrow bytes instead.
+    fn take_until<'a>(&mut self, delim: u8, out: &'a mut [u8]) -> Option<&'a [u8]> {
+        let available = self.available();
+        let len = (0..available).find(|idx| self[*idx] == delim)?;
+
+        if len > out.len() {
+            return None;
+        }
+
+        let out = &mut out[..len];
+        self.read_slice(out);
+        unsafe {
+            self.consume(1);
+        }
+        Some(out)
+    }
+
+    ///Returns whether whole frame, prefixed with 4 byte big endian length, is available, without consuming it.
+    ///
+    ///Frame format is the same as written by `adapter::Framed`.
+    fn has_frame_u32(&self) -> bool {
+        let available = self.available();
+        if available < 4 {
+            return false;
+        }
+
+        let len = u32::from_be_bytes([self[0], self[1], self[2], self[3]]);
+        available - 4 >= len as usize
+    }
+
+    ///Copies bytes through first occurrence of `seq` into `out`, consuming them, returning bytes before `seq`.
+    ///
+    ///Returns `None` without consuming anything if there is no whole `seq` or bytes don't fit `out`.
+    fn read_until_seq<'a>(&mut self, out: &'a mut [u8], seq: &[u8]) -> Option<&'a [u8]> {
+        let end = (self.available() + 1).checked_sub(seq.len())?;
+        let len = (0..end).find(|idx| (0..seq.len()).all(|offset| self[idx + offset] == seq[offset]))?;
+
+        if len + seq.len() > out.len() {
+            return None;
+        }
+
+        self.read_slice(&mut out[..len + seq.len()]);
+        Some(&out[..len])
+    }
+
+    ///Reads bytes into `out` while `pred` returns true, returning number of read bytes.
+    ///
+    ///Stops when `out` is full, leaving byte, that fails `pred`, unconsumed.
+    fn read_while<F: FnMut(u8) -> bool>(&mut self, out: &mut [u8], mut pred: F) -> usize {
+        let max = cmp::min(self.available(), out.len());
+        let len = (0..max).find(|idx| !pred(self[*idx])).unwrap_or(max);
+        self.read_slice(&mut out[..len])
+    }
+
+    ///Reads LEB128 length prefix followed by that many bytes into `out`, returning payload.
+    ///
+    ///Returns `None` without consuming anything if prefix or payload is incomplete, prefix is
+    ///malformed or payload doesn't fit `out`.
+    fn read_lp_varint<'a>(&mut self, out: &'a mut [u8]) -> Option<&'a [u8]> {
+        const MAX_PREFIX: usize = 10;
+        let available = self.available();
+        let mut len = 0u64;
+        let mut prefix = 0;
+
+        loop {
+            if prefix == available || prefix == MAX_PREFIX {
+                return None;
+            }
+
+            let byte = self[prefix];
+            if prefix == MAX_PREFIX - 1 && byte > 1 {
+                return None;
+            }
+
+            len |= ((byte & 0x7f) as u64) << (7 * prefix);
+            prefix += 1;
+
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+
+        if len > (available - prefix) as u64 || len > out.len() as u64 {
+            return None;
+        }
+
+        let out = &mut out[..len as usize];
+        unsafe {
+            self.consume(prefix);
+        }
+        self.read_slice(out);
+        Some(out)
+    }
+
+    ///Decodes `(count, byte)` pairs, written by `WriteBuf::write_rle`, into `out`, returning number of decoded bytes.
+    ///
+    ///Only complete pairs are consumed, decoding stops at first run that doesn't fit `out`.
+    fn read_rle(&mut self, out: &mut [u8]) -> usize {
+        let mut decoded = 0;
+
+        while self.available() >= 2 {
+            let count = self[0] as usize;
+            if count > out.len() - decoded {
+                break;
+            }
+
+            let byte = self[1];
+            for dst in &mut out[decoded..decoded + count] {
+                *dst = byte;
+            }
+
+            unsafe {
+                self.consume(2);
+            }
+            decoded += count;
+        }
+
+        decoded
+    }
+
+    ///Reads samples written by `WriteBuf::write_deltas` into `out`, returning number of read samples.
+    ///
+    ///Buffer is expected to start with first sample of sequence.
+    ///Stops once `out` is full or at first incomplete sample, which is not consumed.
+    fn read_deltas(&mut self, out: &mut [i32]) -> usize {
+        let mut count = 0;
+
+        while count < out.len() {
+            let (sample, len) = match count {
+                0 if self.available() >= 4 => (i32::from_le_bytes([self[0], self[1], self[2], self[3]]), 4),
+                0 => break,
+                _ => match decode_zigzag(self) {
+                    Some((delta, len)) => (out[count - 1].wrapping_add(delta), len),
+                    None => break,
+                },
+            };
+
+            unsafe {
+                self.consume(len);
+            }
+            out[count] = sample;
+            count += 1;
+        }
+
+        count
+    }
+
+    ///Reads alternating bytes into `a` and `b`, returning total number of read bytes.
+    ///
+    ///Bytes are consumed as `a[0], b[0], a[1], b[1], ...`, stopping once either slice is full,
+    ///hence when available number of bytes is odd, last byte goes into `a`.
+    fn read_deinterleaved(&mut self, a: &mut [u8], b: &mut [u8]) -> usize {
+        let read_len = cmp::min(self.available(), cmp::min(a.len().saturating_mul(2), b.len().saturating_mul(2).saturating_add(1)));
+
+        for idx in 0..read_len {
+            match idx % 2 {
+                0 => a[idx / 2] = self[idx],
+                _ => b[idx / 2] = self[idx],
+            }
+        }
+
+        unsafe {
+            self.consume(read_len);
+        }
+        read_len
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Read position, captured by `RewindBuf::snapshot`.
+pub struct Snapshot {
+    pub(crate) read: usize,
+}
+
+///Describes read-able buffer, which can rewind consumed bytes.
+///
+///Suitable only for buffers, which do not destroy bytes on consumption.
+pub trait RewindBuf: ReadBuf {
+    ///Captures current read position.
+    fn snapshot(&self) -> Snapshot;
+
+    ///Restores read position, captured by `snapshot`, returning whether it is successful.
+    ///
+    ///If bytes at snapshotted position are no longer available (e.g. over-written), does nothing,
+    ///returning `false`.
+    fn restore(&mut self, snapshot: Snapshot) -> bool;
+}
+
+///Describes read-able buffer, which allows contiguous lookahead.
+pub trait PeekBuf: ReadBuf {
+    #[inline(always)]
+    ///Returns first `n` readable bytes as single slice, without consuming them.
+    ///
+    ///Returns `None` if there are less than `n` bytes, or they are not contiguous.
+    fn peek_slice(&self, n: usize) -> Option<&[u8]> {
+        self.get_range(0..n)
+    }
 }
 
 ///Extension trait to provide extra functionality
 pub trait ReadBufExt: ReadBuf {
+    #[inline]
+    ///Returns whether there are enough bytes to read value of type `T`.
+    ///
+    ///Same as `read_value`, returns `false` for zero sized types.
+    fn can_read<T: Copy + Sized>(&self) -> bool {
+        let size = mem::size_of::<T>();
+        size != 0 && self.available() >= size
+    }
+
+    #[inline]
+    ///Returns number of whole values of type `T` that can be read.
+    ///
+    ///Same as `read_value`, returns 0 for zero sized types.
+    fn value_count<T: Copy + Sized>(&self) -> usize {
+        match mem::size_of::<T>() {
+            0 => 0,
+            size => self.available() / size,
+        }
+    }
+
+    #[inline]
+    ///Returns iterator over whole values of type `T`, copied from readable bytes without consuming them.
+    ///
+    ///User must guarantee that any bit pattern is valid for `T`.
+    ///Same as `read_value`, yields nothing for zero sized types.
+    unsafe fn values<T: Copy + Sized>(&self) -> iter::Values<'_, Self, T> {
+        iter::Values::new(self)
+    }
+
     #[inline]
     ///Reads value into storage.
     ///
@@ -155,10 +751,80 @@ pub trait ReadBufExt: ReadBuf {
             0
         }
     }
+
+    #[inline]
+    ///Reads exactly `N` bytes into owned array.
+    ///
+    ///If not enough bytes, does nothing, returning `None`
+    fn take_array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        if self.available() < N {
+            return None;
+        }
+
+        let mut result = [0u8; N];
+        if N > 0 {
+            unsafe {
+                self.read(result.as_mut_ptr(), N);
+            }
+        }
+
+        Some(result)
+    }
+
+    ///Copies readable bytes into both `a` and `b`, consuming them, returning number of copied bytes.
+    ///
+    ///Number of bytes is limited by `available()` and space of smaller sink.
+    fn broadcast<W1: WriteBuf, W2: WriteBuf>(&mut self, a: &mut W1, b: &mut W2) -> usize {
+        let mut chunk = [0u8; 64];
+        let size = cmp::min(self.available(), cmp::min(a.remaining(), b.remaining()));
+        let mut offset = 0;
+
+        while offset < size {
+            let chunk = &mut chunk[..cmp::min(size - offset, 64)];
+            for (idx, byte) in chunk.iter_mut().enumerate() {
+                *byte = self[offset + idx];
+            }
+
+            a.write_slice(chunk);
+            b.write_slice(chunk);
+            offset += chunk.len();
+        }
+
+        unsafe {
+            self.consume(size);
+        }
+        size
+    }
 }
 
 impl<T: ReadBuf> ReadBufExt for T {}
 
+macro_rules! impl_write_int_slice {
+    ($($typ:ident => $le:ident, $be:ident;)*) => {$(
+        ///Writes each element in little endian byte order, returning number of written bytes.
+        ///
+        ///Stops at first element that cannot fit.
+        fn $le(&mut self, vals: &[$typ]) -> usize {
+            let count = cmp::min(vals.len(), self.remaining() / mem::size_of::<$typ>());
+            for val in &vals[..count] {
+                self.write_slice(&val.to_le_bytes());
+            }
+            count * mem::size_of::<$typ>()
+        }
+
+        ///Writes each element in big endian byte order, returning number of written bytes.
+        ///
+        ///Stops at first element that cannot fit.
+        fn $be(&mut self, vals: &[$typ]) -> usize {
+            let count = cmp::min(vals.len(), self.remaining() / mem::size_of::<$typ>());
+            for val in &vals[..count] {
+                self.write_slice(&val.to_be_bytes());
+            }
+            count * mem::size_of::<$typ>()
+        }
+    )*};
+}
+
 ///Describes write-able buffer
 pub trait WriteBuf: Buf {
     #[inline(always)]
@@ -195,10 +861,339 @@ pub trait WriteBuf: Buf {
 
         write_len
     }
+
+    #[cfg(feature = "std")]
+    ///Performs single read from `r` into buffer, returning number of read bytes.
+    ///
+    ///Default implementation reads up to 64 bytes through stack memory.
+    fn read_from<R: std::io::Read>(&mut self, r: &mut R) -> std::io::Result<usize> {
+        let mut chunk = [0u8; 64];
+        let chunk = &mut chunk[..cmp::min(self.remaining(), 64)];
+        if chunk.is_empty() {
+            return Ok(0);
+        }
+
+        let size = cmp::min(r.read(chunk)?, chunk.len());
+        Ok(self.write_slice(&chunk[..size]))
+    }
+
+    #[inline]
+    ///Writes single byte, returning whether it fits.
+    fn push(&mut self, byte: u8) -> bool {
+        self.write_slice(&[byte]) == 1
+    }
+
+    #[inline]
+    ///Appends `Buf::xor_checksum` of written bytes, returning whether it fits.
+    fn finalize_xor(&mut self) -> bool {
+        let checksum = self.xor_checksum();
+        self.push(checksum)
+    }
+
+    ///Packs `bits` into bytes, most significant bit first, returning number of written bytes.
+    ///
+    ///Last byte is padded with zero bits.
+    ///Stops at first byte that cannot fit.
+    fn write_bitmap<I: IntoIterator<Item = bool>>(&mut self, bits: I) -> usize {
+        let mut written = 0;
+        let mut byte = 0u8;
+        let mut len = 0;
+
+        for bit in bits {
+            byte |= (bit as u8) << (7 - len);
+            len += 1;
+
+            if len == 8 {
+                if !self.push(byte) {
+                    return written;
+                }
+                written += 1;
+                byte = 0;
+                len = 0;
+            }
+        }
+
+        if len > 0 && self.push(byte) {
+            written += 1;
+        }
+
+        written
+    }
+
+    ///Writes supplied slice in reverse order, returning number of written bytes.
+    ///
+    ///Allows partial writes, in which case only last bytes of slice are written.
+    fn write_slice_rev(&mut self, bytes: &[u8]) -> usize {
+        let write_len = cmp::min(bytes.len(), self.remaining());
+
+        for byte in bytes.iter().rev().take(write_len) {
+            unsafe {
+                self.write(byte, 1);
+            }
+        }
+
+        write_len
+    }
+
+    ///Writes bytes of `a` and `b` interleaved as `a[0], b[0], a[1], b[1], ...`, returning number of written bytes.
+    ///
+    ///Stops at first missing byte, hence if `a` is longer, its byte after end of `b` is still
+    ///written, while excess of `b` is never written.
+    ///Allows partial writes, when there is not enough space.
+    fn write_interleaved(&mut self, a: &[u8], b: &[u8]) -> usize {
+        let write_len = cmp::min(self.remaining(), cmp::min(a.len().saturating_mul(2), b.len().saturating_mul(2).saturating_add(1)));
+
+        for idx in 0..write_len {
+            let byte = match idx % 2 {
+                0 => &a[idx / 2],
+                _ => &b[idx / 2],
+            };
+
+            unsafe {
+                self.write(byte, 1);
+            }
+        }
+
+        write_len
+    }
+
+    impl_write_int_slice!(
+        u16 => write_u16_slice_le, write_u16_slice_be;
+        u32 => write_u32_slice_le, write_u32_slice_be;
+    );
+
+    ///Writes delta encoded `samples`, returning number of written bytes.
+    ///
+    ///First sample is written as 4 byte little endian integer, while every next one as difference
+    ///with previous sample, encoded as zigzag LEB128 varint.
+    ///Stops at first sample that cannot fit.
+    fn write_deltas(&mut self, samples: &[i32]) -> usize {
+        let mut written = 0;
+        let mut prev = 0i32;
+
+        for (idx, sample) in samples.iter().enumerate() {
+            let mut varint = [0u8; 5];
+            let first = sample.to_le_bytes();
+            let encoded = match idx {
+                0 => &first[..],
+                _ => encode_zigzag(&mut varint, sample.wrapping_sub(prev)),
+            };
+
+            if self.remaining() < encoded.len() {
+                break;
+            }
+
+            written += self.write_slice(encoded);
+            prev = *sample;
+        }
+
+        written
+    }
+
+    ///Writes `bytes` as field of fixed `width`, returning number of written bytes.
+    ///
+    ///`bytes` longer than `width` are truncated, while shorter are followed by `pad` up to `width`.
+    ///Allows partial writes, hence always writes `min(width, remaining())` bytes.
+    fn write_field(&mut self, bytes: &[u8], width: usize, pad: u8) -> usize {
+        let write_len = cmp::min(width, self.remaining());
+        let field_len = cmp::min(bytes.len(), write_len);
+
+        self.write_slice(&bytes[..field_len]);
+        for _ in field_len..write_len {
+            unsafe {
+                self.write(&pad, 1);
+            }
+        }
+
+        write_len
+    }
+
+    ///Writes run-length encoded `data` as `(count, byte)` pairs, returning number of encoded bytes of `data`.
+    ///
+    ///Runs longer than 255 are split into multiple pairs.
+    ///Encoding stops once there is no space for next pair.
+    fn write_rle(&mut self, data: &[u8]) -> usize {
+        let mut encoded = 0;
+
+        while encoded < data.len() && self.remaining() >= 2 {
+            let byte = data[encoded];
+            let count = data[encoded..].iter().take(u8::max_value() as usize).take_while(|next| **next == byte).count();
+
+            self.write_slice(&[count as u8, byte]);
+            encoded += count;
+        }
+
+        encoded
+    }
+
+    ///Writes decimal ASCII representation of unsigned integer, returning number of written bytes.
+    ///
+    ///If it cannot fit, does nothing, returning 0
+    fn write_uint(&mut self, value: u64) -> usize {
+        let mut digits = [0u8; 20];
+        let digits = format_decimal(&mut digits, value);
+
+        if self.remaining() < digits.len() {
+            return 0;
+        }
+
+        self.write_slice(digits)
+    }
+
+    ///Writes decimal ASCII representation of signed integer, returning number of written bytes.
+    ///
+    ///If it cannot fit, does nothing, returning 0
+    fn write_int(&mut self, value: i64) -> usize {
+        let mut digits = [0u8; 20];
+        let digits = format_decimal(&mut digits, value.unsigned_abs());
+        let sign = (value < 0) as usize;
+
+        if self.remaining() < sign + digits.len() {
+            return 0;
+        }
+
+        if sign != 0 {
+            self.write_slice(b"-");
+        }
+        sign + self.write_slice(digits)
+    }
+
+    ///Writes `pad` bytes until `len` is multiple of `alignment`, returning number of written bytes.
+    ///
+    ///`alignment` must be power of two.
+    ///
+    ///If padding cannot fit, does nothing, returning 0
+    fn align_to(&mut self, alignment: usize, pad: u8) -> usize {
+        debug_assert!(alignment.is_power_of_two(), "Alignment is not power of 2");
+        let padding = self.len().wrapping_neg() & (alignment - 1);
+
+        if padding == 0 || self.remaining() < padding {
+            return 0;
+        }
+
+        for _ in 0..padding {
+            unsafe {
+                self.write(&pad, 1);
+            }
+        }
+
+        padding
+    }
+
+    #[inline]
+    ///Returns smallest offset, not less than `len`, that is multiple of `align`.
+    ///
+    ///`align` must be power of two.
+    fn next_aligned_offset(&self, align: usize) -> usize {
+        debug_assert!(align.is_power_of_two(), "Alignment is not power of 2");
+        (self.len() + align - 1) & !(align - 1)
+    }
+}
+
+#[cfg(feature = "std")]
+//Performs single write of `bytes` for `ReadBuf::write_to`, retrying on interruption.
+//
+//Returns number of accepted bytes, clamped to `bytes.len()`, or 0 if writing should stop, which
+//includes error after `written` bytes are already consumed.
+pub(crate) fn write_step<W: std::io::Write>(w: &mut W, bytes: &[u8], written: usize) -> std::io::Result<usize> {
+    loop {
+        match w.write(bytes) {
+            Ok(accepted) => break Ok(cmp::min(accepted, bytes.len())),
+            Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(_) if written > 0 => break Ok(0),
+            Err(error) => break Err(error),
+        }
+    }
+}
+
+fn format_decimal(buf: &mut [u8; 20], mut value: u64) -> &[u8] {
+    let mut idx = buf.len();
+
+    loop {
+        idx -= 1;
+        buf[idx] = b'0' + (value % 10) as u8;
+        value /= 10;
+
+        if value == 0 {
+            break &buf[idx..];
+        }
+    }
+}
+
+fn encode_zigzag(buf: &mut [u8; 5], value: i32) -> &[u8] {
+    let mut value = ((value << 1) ^ (value >> 31)) as u32;
+    let mut idx = 0;
+
+    loop {
+        buf[idx] = (value & 0x7f) as u8;
+        value >>= 7;
+        idx += 1;
+
+        if value == 0 {
+            break &buf[..idx];
+        }
+        buf[idx - 1] |= 0x80;
+    }
+}
+
+//Returns decoded value and its length, if varint is complete
+fn decode_zigzag<R: ReadBuf>(buf: &R) -> Option<(i32, usize)> {
+    let available = buf.available();
+    let mut value = 0u32;
+    let mut len = 0;
+
+    loop {
+        if len == available || len == 5 {
+            return None;
+        }
+
+        let byte = buf[len];
+        value |= ((byte & 0x7f) as u32) << (7 * len);
+        len += 1;
+
+        if byte & 0x80 == 0 {
+            break Some((((value >> 1) as i32) ^ -((value & 1) as i32), len));
+        }
+    }
+}
+
+macro_rules! impl_write_int_ext {
+    ($($typ:ident => $le:ident, $be:ident;)*) => {$(
+        #[inline]
+        ///Writes integer in little endian byte order, returning number of written bytes.
+        ///
+        ///If it cannot fit, does nothing, returning 0
+        fn $le(&mut self, val: $typ) -> usize {
+            match self.put_array(val.to_le_bytes()) {
+                true => mem::size_of::<$typ>(),
+                false => 0,
+            }
+        }
+
+        #[inline]
+        ///Writes integer in big endian byte order, returning number of written bytes.
+        ///
+        ///If it cannot fit, does nothing, returning 0
+        fn $be(&mut self, val: $typ) -> usize {
+            match self.put_array(val.to_be_bytes()) {
+                true => mem::size_of::<$typ>(),
+                false => 0,
+            }
+        }
+    )*};
 }
 
 ///Extension trait to provide extra functionality
 pub trait WriteBufExt: WriteBuf {
+    #[inline]
+    ///Returns whether there is enough space to write value of type `T`.
+    ///
+    ///Same as `write_value`, returns `false` for zero sized types.
+    fn can_write<T: Copy + Sized>(&self) -> bool {
+        let size = mem::size_of::<T>();
+        size != 0 && self.remaining() >= size
+    }
+
     #[inline]
     ///Writes supplied value by performing bit copy, advancing length and returning number of bytes written.
     ///
@@ -215,6 +1210,75 @@ pub trait WriteBufExt: WriteBuf {
             0
         }
     }
+
+    #[inline]
+    ///Writes whole array, returning `true` on success.
+    ///
+    ///If array cannot fit, does nothing, returning `false`
+    fn put_array<const N: usize>(&mut self, arr: [u8; N]) -> bool {
+        if self.remaining() < N {
+            return false;
+        }
+
+        if N > 0 {
+            unsafe {
+                self.write(arr.as_ptr(), N);
+            }
+        }
+
+        true
+    }
+
+    ///Moves up to `max` bytes from `src`, returning number of moved bytes.
+    ///
+    ///Number of bytes is limited by `remaining` and `src.available()`.
+    ///
+    ///Bytes are copied directly from `src`, when they are available as single slice (i.e. `src` is
+    ///contiguous), otherwise they are copied in chunks through stack memory.
+    fn fill_from<R: ReadBuf>(&mut self, src: &mut R, max: usize) -> usize {
+        let size = cmp::min(max, cmp::min(self.remaining(), src.available()));
+
+        if let Some(bytes) = src.get_range(0..size) {
+            let size = self.write_slice(bytes);
+            unsafe {
+                src.consume(size);
+            }
+            return size;
+        }
+
+        let mut chunk = [0u8; 64];
+        let mut left = size;
+        while left > 0 {
+            let chunk = &mut chunk[..cmp::min(left, 64)];
+            src.read_slice(chunk);
+            self.write_slice(chunk);
+            left -= chunk.len();
+        }
+
+        size
+    }
+
+    impl_write_int_ext!(
+        u16 => write_u16_le, write_u16_be;
+        i16 => write_i16_le, write_i16_be;
+        u32 => write_u32_le, write_u32_be;
+        i32 => write_i32_le, write_i32_be;
+        u64 => write_u64_le, write_u64_be;
+        i64 => write_i64_le, write_i64_be;
+    );
 }
 
 impl<T: WriteBuf> WriteBufExt for T {}
+
+///Describes write-able buffer that grows on demand.
+pub trait GrowBuf: WriteBuf + DynBuf {
+    #[inline]
+    ///Writes whole slice, reserving space beforehand, if needed.
+    fn write_all_grow(&mut self, bytes: &[u8]) {
+        self.reserve(bytes.len());
+        let written = self.write_slice(bytes);
+        debug_assert_eq!(written, bytes.len());
+    }
+}
+
+impl<T: WriteBuf + DynBuf> GrowBuf for T {}
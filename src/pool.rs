@@ -0,0 +1,81 @@
+//!Pool of reusable static buffers
+
+use core::{array, ops};
+use core::cell::{Cell, UnsafeCell};
+use crate::stack::Buffer;
+
+///Fixed set of `N` buffers, lent out one at a time.
+///
+///Buffer is returned into pool once `PooledBuffer` is dropped, with its content cleared.
+pub struct BufferPool<S: Sized, const N: usize> {
+    buffers: [UnsafeCell<Buffer<S>>; N],
+    used: [Cell<bool>; N],
+}
+
+impl<S: Sized, const N: usize> BufferPool<S, N> {
+    #[inline]
+    ///Creates new instance with all buffers available.
+    pub fn new() -> Self {
+        Self {
+            buffers: array::from_fn(|_| UnsafeCell::new(Buffer::new())),
+            used: array::from_fn(|_| Cell::new(false)),
+        }
+    }
+
+    #[inline]
+    ///Returns number of buffers that are not acquired.
+    pub fn available(&self) -> usize {
+        self.used.iter().filter(|used| !used.get()).count()
+    }
+
+    ///Acquires empty buffer, if any is available.
+    pub fn acquire(&self) -> Option<PooledBuffer<'_, S>> {
+        let idx = self.used.iter().position(|used| !used.get())?;
+        self.used[idx].set(true);
+
+        Some(PooledBuffer {
+            //Buffer is lent out only once, until its guard is dropped
+            buffer: unsafe { &mut *self.buffers[idx].get() },
+            used: &self.used[idx],
+        })
+    }
+}
+
+impl<S: Sized, const N: usize> Default for BufferPool<S, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///Buffer acquired from `BufferPool`.
+///
+///Dereferences to `Buffer`, which is cleared and released into pool on drop.
+pub struct PooledBuffer<'a, S: Sized> {
+    buffer: &'a mut Buffer<S>,
+    used: &'a Cell<bool>,
+}
+
+impl<'a, S: Sized> ops::Deref for PooledBuffer<'a, S> {
+    type Target = Buffer<S>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.buffer
+    }
+}
+
+impl<'a, S: Sized> ops::DerefMut for PooledBuffer<'a, S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.buffer
+    }
+}
+
+impl<'a, S: Sized> Drop for PooledBuffer<'a, S> {
+    #[inline]
+    fn drop(&mut self) {
+        self.buffer.consume_all();
+        self.used.set(false);
+    }
+}
@@ -0,0 +1,178 @@
+//!Rolling computations over circular buffer
+//!
+//!Helpers in this module wrap `Ring` and keep their state up to date with every write and
+//!consume, so that it never needs to rescan buffer's content.
+
+use core::cmp;
+use crate::{Buf, ReadBuf, WriteBuf};
+use crate::stack::Ring;
+
+const BASE: u64 = 257;
+//Modular inverse of BASE, computed using Newton's method (each step doubles number of correct bits)
+const BASE_INV: u64 = {
+    let mut inv = BASE;
+    let mut idx = 0;
+    while idx < 5 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(BASE.wrapping_mul(inv)));
+        idx += 1;
+    }
+    inv
+};
+
+///Computes polynomial hash of `bytes`, as it would be computed by `RollingHash`.
+pub fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |hash, byte| hash.wrapping_mul(BASE).wrapping_add(*byte as u64))
+}
+
+///Rolling polynomial hash over content of `Ring`.
+///
+///Hash is updated in `O(1)` per each written or consumed byte, including bytes that are dropped
+///when ring is overwritten.
+///At any moment it is equal to `rolling::hash` of ring's readable bytes in logical order, which
+///makes it suitable for content-defined chunking algorithms.
+pub struct RollingHash<S: Sized> {
+    ring: Ring<S>,
+    hash: u64,
+    //BASE^len
+    pow: u64,
+}
+
+impl<S: Sized> RollingHash<S> {
+    #[inline]
+    ///Creates new instance with empty ring.
+    pub const fn new() -> Self {
+        Self {
+            ring: Ring::new(),
+            hash: 0,
+            pow: 1,
+        }
+    }
+
+    #[inline]
+    ///Returns hash of current window.
+    pub const fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    #[inline]
+    ///Returns reference to underlying ring.
+    pub const fn as_ring(&self) -> &Ring<S> {
+        &self.ring
+    }
+
+    #[inline]
+    ///Returns underlying ring.
+    pub fn into_inner(self) -> Ring<S> {
+        self.ring
+    }
+
+    fn remove_oldest(&mut self, step: usize) {
+        for idx in 0..step {
+            self.pow = self.pow.wrapping_mul(BASE_INV);
+            self.hash = self.hash.wrapping_sub((self.ring[idx] as u64).wrapping_mul(self.pow));
+        }
+
+        unsafe {
+            self.ring.consume(step);
+        }
+    }
+
+    ///Writes bytes into ring, sliding window over oldest bytes, if there is not enough space.
+    ///
+    ///Same as `Ring` keeps at most last `capacity` bytes, returning number of kept bytes.
+    pub fn write_slice(&mut self, bytes: &[u8]) -> usize {
+        let bytes = &bytes[bytes.len().saturating_sub(self.ring.capacity())..];
+        let overflow = (self.ring.len() + bytes.len()).saturating_sub(self.ring.capacity());
+        self.remove_oldest(overflow);
+
+        for byte in bytes {
+            self.hash = self.hash.wrapping_mul(BASE).wrapping_add(*byte as u64);
+            self.pow = self.pow.wrapping_mul(BASE);
+        }
+
+        self.ring.write_slice(bytes)
+    }
+
+    ///Consumes up to `step` oldest bytes, returning number of consumed bytes.
+    pub fn consume(&mut self, step: usize) -> usize {
+        let step = cmp::min(step, self.ring.len());
+        self.remove_oldest(step);
+        step
+    }
+}
+
+///Running sum of bytes inside `Ring`.
+///
+///Sum is updated with every written or consumed byte, including bytes that are dropped when ring
+///is overwritten, which makes `Ring` a sliding window accumulator.
+pub struct WindowSum<S: Sized> {
+    ring: Ring<S>,
+    sum: u64,
+}
+
+impl<S: Sized> WindowSum<S> {
+    #[inline]
+    ///Creates new instance with empty ring.
+    pub const fn new() -> Self {
+        Self {
+            ring: Ring::new(),
+            sum: 0,
+        }
+    }
+
+    #[inline]
+    ///Returns sum of bytes in current window.
+    pub const fn sum(&self) -> u64 {
+        self.sum
+    }
+
+    #[inline]
+    ///Returns mean of bytes in current window, or 0 if it is empty.
+    pub fn mean(&self) -> f64 {
+        match self.ring.len() {
+            0 => 0.0,
+            len => self.sum as f64 / len as f64,
+        }
+    }
+
+    #[inline]
+    ///Returns reference to underlying ring.
+    pub const fn as_ring(&self) -> &Ring<S> {
+        &self.ring
+    }
+
+    #[inline]
+    ///Returns underlying ring.
+    pub fn into_inner(self) -> Ring<S> {
+        self.ring
+    }
+
+    fn remove_oldest(&mut self, step: usize) {
+        for idx in 0..step {
+            self.sum -= self.ring[idx] as u64;
+        }
+
+        unsafe {
+            self.ring.consume(step);
+        }
+    }
+
+    ///Writes bytes into ring, sliding window over oldest bytes, if there is not enough space.
+    ///
+    ///Same as `Ring` keeps at most last `capacity` bytes, returning number of kept bytes.
+    pub fn write_slice(&mut self, bytes: &[u8]) -> usize {
+        let bytes = &bytes[bytes.len().saturating_sub(self.ring.capacity())..];
+        let overflow = (self.ring.len() + bytes.len()).saturating_sub(self.ring.capacity());
+        self.remove_oldest(overflow);
+
+        self.sum += bytes.iter().map(|byte| *byte as u64).sum::<u64>();
+        self.ring.write_slice(bytes)
+    }
+
+    ///Consumes up to `step` oldest bytes, returning number of consumed bytes.
+    pub fn consume(&mut self, step: usize) -> usize {
+        let step = cmp::min(step, self.ring.len());
+        self.remove_oldest(step);
+        step
+    }
+}
@@ -0,0 +1,61 @@
+//!Typed message queue over circular buffer
+
+use core::{marker, mem};
+use crate::{Buf, ReadBufExt, WriteBufExt};
+use crate::stack::Ring;
+
+///Bounded FIFO queue of values `T`, stored as bytes inside `Ring`.
+///
+///Unlike `Ring`, it never over-writes unread values.
+pub struct Queue<T: Copy, S: Sized> {
+    ring: Ring<S>,
+    value: marker::PhantomData<T>,
+}
+
+impl<T: Copy, S: Sized> Queue<T, S> {
+    #[inline]
+    ///Creates new empty instance
+    pub const fn new() -> Self {
+        Self {
+            ring: Ring::new(),
+            value: marker::PhantomData,
+        }
+    }
+
+    #[inline]
+    ///Returns number of values in queue.
+    pub fn len(&self) -> usize {
+        match mem::size_of::<T>() {
+            0 => 0,
+            size => self.ring.len() / size,
+        }
+    }
+
+    #[inline]
+    ///Returns whether queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.ring.is_empty()
+    }
+
+    ///Pushes value to the back of queue, returning whether it fits.
+    ///
+    ///Zero sized values are never pushed.
+    pub fn try_push(&mut self, value: T) -> bool {
+        let size = mem::size_of::<T>();
+        if self.ring.capacity() - self.ring.len() < size {
+            return false;
+        }
+
+        self.ring.write_value(&value) != 0
+    }
+
+    ///Pops value from the front of queue.
+    pub fn try_pop(&mut self) -> Option<T> {
+        let mut value = mem::MaybeUninit::<T>::uninit();
+        match self.ring.read_value(&mut value) {
+            0 => None,
+            //Only bytes of valid `T` are ever written
+            _ => Some(unsafe { value.assume_init() }),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! Stack based buffer
 
 use core::{cmp, fmt, slice, mem, ptr, ops};
-use crate::{Buf, ContBuf, ReadBuf, WriteBuf};
+use crate::{Buf, ContBuf, ReadBuf, PeekBuf, RewindBuf, Snapshot, WriteBuf, ReadBufExt, WriteBufExt, CapacityError};
 
 ///Static buffer to raw bytes
 ///
@@ -21,6 +21,32 @@ use crate::{Buf, ContBuf, ReadBuf, WriteBuf};
 pub struct Buffer<T: Sized> {
     inner: mem::MaybeUninit<T>,
     cursor: usize, //number of bytes written
+    init: usize, //bytes before it are initialized, even if past cursor
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Describes invariant violated by buffer's parts.
+pub enum PartsError {
+    ///Cursor is over capacity.
+    CursorOverflow,
+    ///Read position is ahead of write cursor.
+    ReadOverflow,
+    ///Number of unread bytes is over capacity.
+    LenOverflow,
+}
+
+impl fmt::Display for PartsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartsError::CursorOverflow => f.write_str("Cursor is over capacity"),
+            PartsError::ReadOverflow => f.write_str("Read position is ahead of cursor"),
+            PartsError::LenOverflow => f.write_str("Unread length is over capacity"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PartsError {
 }
 
 impl<S: Sized> Buffer<S> {
@@ -30,6 +56,19 @@ impl<S: Sized> Buffer<S> {
         Self {
             inner: mem::MaybeUninit::uninit(),
             cursor: 0,
+            init: 0,
+        }
+    }
+
+    #[inline]
+    ///Creates new instance with zero initialized storage.
+    ///
+    ///Buffer is empty, but all its bytes are initialized, which is required by `reset_full`.
+    pub const fn zeroed() -> Self {
+        Self {
+            inner: mem::MaybeUninit::zeroed(),
+            cursor: 0,
+            init: Self::capacity(),
         }
     }
 
@@ -50,7 +89,32 @@ impl<S: Sized> Buffer<S> {
         Self {
             inner,
             cursor,
+            init: cursor,
+        }
+    }
+
+    #[inline]
+    ///Creates new instance from initialized storage and cursor, validating it.
+    ///
+    ///Returns error if `cursor` is over capacity.
+    pub fn try_from_parts(inner: S, cursor: usize) -> Result<Self, PartsError> {
+        if cursor > Self::capacity() {
+            return Err(PartsError::CursorOverflow);
         }
+
+        Ok(Self {
+            inner: mem::MaybeUninit::new(inner),
+            cursor,
+            init: Self::capacity(),
+        })
+    }
+
+    #[inline]
+    ///Returns underlying storage.
+    ///
+    ///User must guarantee that storage is fully initialized, e.g. whole capacity is written.
+    pub const unsafe fn into_inner(self) -> S {
+        self.inner.assume_init()
     }
 
     #[inline]
@@ -87,6 +151,22 @@ impl<S: Sized> Buffer<S> {
         }
     }
 
+    #[inline]
+    ///Splits already written data into two slices at `mid`.
+    ///
+    ///Panics if `mid` is greater than `len`.
+    pub fn split_at(&self, mid: usize) -> (&[u8], &[u8]) {
+        self.as_slice().split_at(mid)
+    }
+
+    #[inline]
+    ///Splits already written data into two mutable slices at `mid`.
+    ///
+    ///Panics if `mid` is greater than `len`.
+    pub fn split_at_mut(&mut self, mid: usize) -> (&mut [u8], &mut [u8]) {
+        self.as_mut_slice().split_at_mut(mid)
+    }
+
     #[inline]
     ///Shortens the buffer.
     ///
@@ -106,6 +186,24 @@ impl<S: Sized> Buffer<S> {
         self.cursor = cursor
     }
 
+    #[inline]
+    ///Marks whole storage as written, making all `capacity` bytes readable.
+    ///
+    ///Intended to reuse buffer as fixed layout scratch, e.g. created via `zeroed`.
+    ///When used, user must guarantee that all bytes of storage are initialized.
+    pub unsafe fn reset_full(&mut self) {
+        self.cursor = Self::capacity();
+    }
+
+    #[inline]
+    ///Returns alignment of underlying storage, i.e. `mem::align_of::<S>()`.
+    ///
+    ///Storage starts at address aligned to this value, hence value of type `T` can be read in
+    ///place at offset `o` only if `mem::align_of::<T>()` divides both this value and `o`.
+    pub const fn storage_align(&self) -> usize {
+        mem::align_of::<S>()
+    }
+
     #[inline]
     ///Returns buffer overall capacity.
     pub const fn capacity() -> usize {
@@ -117,6 +215,130 @@ impl<S: Sized> Buffer<S> {
     pub const fn len(&self) -> usize {
         self.cursor
     }
+
+    ///Replaces all non-overlapping occurrences of `from` with `to`, returning number of replacements.
+    ///
+    ///When `to` is longer than `from`, replaces occurrences only while result fits capacity,
+    ///stopping at first occurrence that cannot fit.
+    pub fn replace(&mut self, from: &[u8], to: &[u8]) -> usize {
+        if from.is_empty() {
+            return 0;
+        }
+
+        let mut count = 0;
+        let mut idx = 0;
+
+        while idx + from.len() <= self.cursor {
+            if &self.as_slice()[idx..idx + from.len()] != from {
+                idx += 1;
+                continue;
+            }
+
+            if to.len() > from.len() && self.remaining() < to.len() - from.len() {
+                break;
+            }
+
+            let tail_idx = idx + from.len();
+            let tail_len = self.cursor - tail_idx;
+            let ptr = self.as_ptr() as *mut u8;
+            unsafe {
+                ptr::copy(ptr.offset(tail_idx as isize), ptr.offset((idx + to.len()) as isize), tail_len);
+                ptr::copy_nonoverlapping(to.as_ptr(), ptr.offset(idx as isize), to.len());
+            }
+
+            self.cursor = self.cursor - from.len() + to.len();
+            idx += to.len();
+            count += 1;
+        }
+
+        count
+    }
+
+    #[inline]
+    ///Converts written bytes to ASCII upper case in place.
+    ///
+    ///Non-ASCII bytes are left unchanged.
+    pub fn make_ascii_uppercase(&mut self) {
+        self.as_mut_slice().make_ascii_uppercase()
+    }
+
+    #[inline]
+    ///Converts written bytes to ASCII lower case in place.
+    ///
+    ///Non-ASCII bytes are left unchanged.
+    pub fn make_ascii_lowercase(&mut self) {
+        self.as_mut_slice().make_ascii_lowercase()
+    }
+
+    ///Removes leading and trailing ASCII whitespace from written bytes.
+    pub fn trim_ascii(&mut self) {
+        let len = self.as_slice().iter().rev().position(|byte| !byte.is_ascii_whitespace()).map_or(0, |trailing| self.cursor - trailing);
+        self.truncate(len);
+
+        let leading = self.as_slice().iter().position(|byte| !byte.is_ascii_whitespace()).unwrap_or(len);
+        unsafe {
+            self.consume(leading);
+        }
+    }
+
+    #[inline(always)]
+    ///Consumes all written bytes, making buffer empty.
+    ///
+    ///Unlike partial `ReadBuf::consume`, it never moves memory, so when whole content is processed
+    ///at once, e.g. via `as_slice`, prefer it to avoid `memmove` on each read.
+    pub fn consume_all(&mut self) {
+        self.cursor = 0;
+    }
+
+    ///Inserts `bytes` at `index`, shifting following bytes to the right, returning number of inserted bytes.
+    ///
+    ///If `bytes` cannot fit, does nothing, returning 0.
+    ///Panics if `index` is greater than `len`.
+    pub fn insert(&mut self, index: usize, bytes: &[u8]) -> usize {
+        assert!(index <= self.cursor, "Index out of bounds");
+
+        if self.remaining() < bytes.len() {
+            return 0;
+        }
+
+        let ptr = self.as_ptr() as *mut u8;
+        unsafe {
+            ptr::copy(ptr.offset(index as isize), ptr.offset((index + bytes.len()) as isize), self.cursor - index);
+            ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.offset(index as isize), bytes.len());
+        }
+        self.cursor += bytes.len();
+
+        bytes.len()
+    }
+
+    ///Removes bytes within `range`, shifting following bytes to the left.
+    ///
+    ///Panics if `range` is out of bounds.
+    pub fn remove_range(&mut self, range: ops::Range<usize>) {
+        assert!(range.start <= range.end && range.end <= self.cursor, "Range out of bounds");
+
+        let ptr = self.as_ptr() as *mut u8;
+        unsafe {
+            ptr::copy(ptr.offset(range.end as isize), ptr.offset(range.start as isize), self.cursor - range.end);
+        }
+        self.cursor -= range.end - range.start;
+    }
+
+    ///Pulls as many bytes from `src` as fit, returning number of added bytes.
+    ///
+    ///Unread bytes need no compaction, as consumption always shifts them to the beginning.
+    pub fn refill<R: ReadBuf>(&mut self, src: &mut R) -> usize {
+        let size = cmp::min(self.remaining(), src.available());
+
+        if size > 0 {
+            unsafe {
+                src.read(self.as_ptr().offset(self.cursor as isize) as *mut u8, size);
+                self.advance(size);
+            }
+        }
+
+        size
+    }
 }
 
 impl<S: Sized> ops::Index<usize> for Buffer<S> {
@@ -165,6 +387,17 @@ impl<S: Sized> Buf for Buffer<S> {
     fn len(&self) -> usize {
         self.cursor
     }
+
+    #[inline(always)]
+    fn get_range(&self, range: ops::Range<usize>) -> Option<&[u8]> {
+        self.as_slice().get(range)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn to_vec(&self) -> crate::alloc::Vec<u8> {
+        self.as_slice().to_vec()
+    }
 }
 
 impl<S: Sized> WriteBuf for Buffer<S> {
@@ -181,9 +414,28 @@ impl<S: Sized> WriteBuf for Buffer<S> {
     unsafe fn write(&mut self, ptr: *const u8, size: usize) {
         debug_assert!(!ptr.is_null());
 
-        ptr::copy_nonoverlapping(ptr, self.as_ptr().offset(self.cursor as isize) as *mut u8, size);
+        //Source may point into buffer's own storage
+        ptr::copy(ptr, self.as_ptr().offset(self.cursor as isize) as *mut u8, size);
         self.advance(size);
     }
+
+    #[cfg(feature = "std")]
+    ///Reads directly into spare capacity, zeroing only its part that is not yet initialized.
+    fn read_from<R: std::io::Read>(&mut self, r: &mut R) -> std::io::Result<usize> {
+        let init = cmp::max(self.init, self.cursor);
+        let ptr = self.as_ptr() as *mut u8;
+        let spare = unsafe {
+            ptr::write_bytes(ptr.offset(init as isize), 0, Self::capacity() - init);
+            slice::from_raw_parts_mut(ptr.offset(self.cursor as isize), Self::capacity() - self.cursor)
+        };
+        self.init = Self::capacity();
+
+        let size = cmp::min(r.read(spare)?, spare.len());
+        unsafe {
+            self.advance(size);
+        }
+        Ok(size)
+    }
 }
 
 impl<S: Sized> ReadBuf for Buffer<S> {
@@ -209,8 +461,29 @@ impl<S: Sized> ReadBuf for Buffer<S> {
         ptr::copy_nonoverlapping(self.as_ptr(), ptr, size);
         self.consume(size);
     }
+
+    #[cfg(feature = "std")]
+    fn write_to<W: std::io::Write>(&mut self, w: &mut W) -> std::io::Result<usize> {
+        let mut written = 0;
+
+        while self.cursor > 0 {
+            let accepted = crate::write_step(w, self.as_slice(), written)?;
+            if accepted == 0 {
+                break;
+            }
+
+            unsafe {
+                self.consume(accepted);
+            }
+            written += accepted;
+        }
+
+        Ok(written)
+    }
 }
 
+impl<S: Sized> PeekBuf for Buffer<S> {}
+
 impl<S: Sized> ContBuf for Buffer<S> {
     #[inline(always)]
     fn as_read_slice(&self) -> &[u8] {
@@ -224,6 +497,8 @@ impl<S: Sized> ContBuf for Buffer<S> {
 
     #[inline(always)]
     fn as_write_slice(&mut self) -> &mut [mem::MaybeUninit<u8>] {
+        //Caller may de-initialize these bytes
+        self.init = self.cursor;
         unsafe {
             slice::from_raw_parts_mut(self.as_ptr().offset(self.cursor as isize) as *mut mem::MaybeUninit<u8>, Self::capacity() - self.cursor)
         }
@@ -243,163 +518,991 @@ impl<S: Sized> std::io::Write for Buffer<S> {
     }
 }
 
-///Circular version of `Buffer`
+///Buffer over borrowed memory.
 ///
-///Because `Buffer` is circular, it always has remaining bytes to write.
-///But care must be taken because without consuming already written bytes, it is easy to over-write
-///as ring buffer always has capacity.
-pub struct Ring<T: Sized> {
-    buffer: Buffer<T>,
-    read: usize
+///Behaves the same way as `Buffer`, but instead of owning storage, it operates over memory
+///provided by user (e.g. DMA region), which must outlive the buffer.
+pub struct BufferRef<'a> {
+    inner: &'a mut [mem::MaybeUninit<u8>],
+    cursor: usize, //number of bytes written
+    init: usize, //bytes before it are initialized, even if past cursor
 }
 
-impl<S: Sized> Ring<S> {
+impl<'a> BufferRef<'a> {
     #[inline]
-    ///Creates new instance
-    pub const fn new() -> Self {
-        unsafe {
-            Self::from_parts(Buffer::new(), 0)
+    ///Creates new empty instance over `inner` memory.
+    pub fn new(inner: &'a mut [mem::MaybeUninit<u8>]) -> Self {
+        Self {
+            inner,
+            cursor: 0,
+            init: 0,
         }
     }
 
     #[inline]
-    ///Creates new instance from parts
-    pub const unsafe fn from_parts(buffer: Buffer<S>, read: usize) -> Self {
+    ///Creates new instance from parts.
+    ///
+    ///`cursor` - number of elements written. It is user responsibility to make sure it is not over
+    ///actual capacity and that these bytes are initialized.
+    pub unsafe fn from_parts(inner: &'a mut [mem::MaybeUninit<u8>], cursor: usize) -> Self {
         Self {
-            buffer,
-            read
+            inner,
+            cursor,
+            init: cursor,
         }
     }
 
     #[inline]
-    ///Creates new instance from parts
-    pub const fn into_parts(self) -> (Buffer<S>, usize) {
-        (self.buffer, self.read)
+    ///Splits buffer into parts.
+    pub fn into_parts(self) -> (&'a mut [mem::MaybeUninit<u8>], usize) {
+        (self.inner, self.cursor)
     }
 
     #[inline]
-    const fn mask_idx(idx: usize) -> usize {
-        idx & (Buffer::<S>::capacity() - 1)
+    ///Returns pointer  to the beginning of underlying buffer
+    pub fn as_ptr(&self) -> *const u8 {
+        self.inner.as_ptr() as *const u8
     }
 
-    ///Returns number of available elements
-    pub const fn len(&self) -> usize {
-        self.buffer.cursor - self.read
+    #[inline]
+    ///Returns number of bytes left (not written yet)
+    pub fn remaining(&self) -> usize {
+        self.capacity() - self.cursor
     }
 
-    ///Returns whether buffer is empty.
-    pub const fn is_empty(&self) -> bool {
-        self.buffer.cursor == self.read
+    #[inline]
+    ///Returns slice to already written data.
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe {
+            slice::from_raw_parts(self.as_ptr(), self.cursor)
+        }
     }
 
-    ///Returns whether buffer is full.
-    pub const fn is_full(&self) -> bool {
-        Buffer::<S>::capacity() == self.len()
+    #[inline]
+    ///Returns mutable slice to already written data.
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe {
+            slice::from_raw_parts_mut(self.inner.as_mut_ptr() as *mut u8, self.cursor)
+        }
+    }
+
+    #[inline]
+    ///Shortens the buffer.
+    ///
+    ///Does nothing if new `cursor` is after current position.
+    pub fn truncate(&mut self, cursor: usize) {
+        if cursor < self.cursor {
+            self.cursor = cursor
+        }
+    }
+
+    #[inline]
+    ///Changes written length, without writing.
+    ///
+    ///When used, user must guarantee that these bytes are written.
+    pub unsafe fn set_len(&mut self, cursor: usize) {
+        debug_assert!(cursor <= self.capacity());
+        self.cursor = cursor
+    }
+
+    #[inline]
+    ///Returns buffer overall capacity.
+    pub fn capacity(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline]
+    ///Returns number of bytes written.
+    pub const fn len(&self) -> usize {
+        self.cursor
     }
 }
 
-impl<S: Sized> ops::Index<usize> for Ring<S> {
+impl<'a> ops::Index<usize> for BufferRef<'a> {
     type Output = u8;
 
     #[inline(always)]
-    fn index(&self, mut index: usize) -> &Self::Output {
-        debug_assert!(index < self.len());
-        index = Self::mask_idx(self.read.wrapping_add(index));
-        unsafe {
-            &*self.buffer.as_ptr().offset(index as isize)
-        }
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.as_slice()[index]
     }
 }
 
-impl<S: Sized> ops::IndexMut<usize> for Ring<S> {
+impl<'a> ops::IndexMut<usize> for BufferRef<'a> {
     #[inline(always)]
-    fn index_mut(&mut self, mut index: usize) -> &mut Self::Output {
-        debug_assert!(index < self.len());
-        index = Self::mask_idx(self.read.wrapping_add(index));
-        unsafe {
-            &mut *(self.buffer.as_ptr().offset(index as isize) as *mut _)
-        }
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.as_mut_slice()[index]
     }
 }
 
-impl<S: Sized> Buf for Ring<S> {
+impl<'a> AsRef<[u8]> for BufferRef<'a> {
     #[inline(always)]
-    fn capacity(&self) -> usize {
-        Buffer::<S>::capacity()
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
     }
+}
 
+impl<'a> fmt::Debug for BufferRef<'a> {
     #[inline(always)]
-    fn len(&self) -> usize {
-        Self::len(self)
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.as_slice().iter()).finish()
     }
 }
 
-impl<S: Sized> ReadBuf for Ring<S> {
+impl<'a> Buf for BufferRef<'a> {
     #[inline(always)]
-    fn available(&self) -> usize {
-        Self::len(self)
+    fn capacity(&self) -> usize {
+        Self::capacity(self)
     }
 
-    #[inline]
-    unsafe fn consume(&mut self, step: usize) {
-        self.read = self.read.wrapping_add(step);
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.cursor
     }
 
-    unsafe fn read(&mut self, ptr: *mut u8, mut size: usize) {
-        debug_assert!(!ptr.is_null());
-        debug_assert!((Buffer::<S>::capacity() & (Buffer::<S>::capacity() - 1)) == 0, "Capacity is not power of 2");
-        let idx = Self::mask_idx(self.read);
-        let read_span = cmp::min(Buffer::<S>::capacity() - idx, size);
-
-        ptr::copy_nonoverlapping(self.buffer.as_ptr().offset(idx as isize), ptr, read_span);
-        self.consume(read_span);
-        size -= read_span;
-
-        if size > 0 {
-            let avail_size = cmp::min(size, self.available());
-            if avail_size > 0 {
-                ptr::copy_nonoverlapping(self.buffer.as_ptr(), ptr.offset(read_span as isize), avail_size);
-                self.consume(avail_size);
-            }
-        }
+    #[inline(always)]
+    fn get_range(&self, range: ops::Range<usize>) -> Option<&[u8]> {
+        self.as_slice().get(range)
     }
 }
 
-impl<S: Sized> WriteBuf for Ring<S> {
+impl<'a> WriteBuf for BufferRef<'a> {
     #[inline(always)]
     fn remaining(&self) -> usize {
-        Buffer::<S>::capacity()
+        Self::remaining(self)
     }
 
     #[inline(always)]
     unsafe fn advance(&mut self, step: usize) {
-        self.buffer.cursor = self.buffer.cursor.wrapping_add(step);
-
-        let read_span = self.buffer.cursor - self.read;
-        if read_span > Buffer::<S>::capacity() {
-            //consume over-written bytes
-            self.consume(read_span - Buffer::<S>::capacity());
-        }
+        self.set_len(self.cursor + step);
     }
 
-    unsafe fn write(&mut self, ptr: *const u8, mut size: usize) {
+    unsafe fn write(&mut self, ptr: *const u8, size: usize) {
+        debug_assert!(!ptr.is_null());
+
+        //Source may point into buffer's own storage
+        ptr::copy(ptr, self.inner.as_mut_ptr().offset(self.cursor as isize) as *mut u8, size);
+        self.advance(size);
+    }
+
+    #[cfg(feature = "std")]
+    ///Reads directly into spare capacity, zeroing only its part that is not yet initialized.
+    fn read_from<R: std::io::Read>(&mut self, r: &mut R) -> std::io::Result<usize> {
+        let capacity = self.capacity();
+        let init = cmp::max(self.init, self.cursor);
+        let ptr = self.inner.as_mut_ptr() as *mut u8;
+        let spare = unsafe {
+            ptr::write_bytes(ptr.offset(init as isize), 0, capacity - init);
+            slice::from_raw_parts_mut(ptr.offset(self.cursor as isize), capacity - self.cursor)
+        };
+        self.init = capacity;
+
+        let size = cmp::min(r.read(spare)?, spare.len());
+        unsafe {
+            self.advance(size);
+        }
+        Ok(size)
+    }
+}
+
+impl<'a> ReadBuf for BufferRef<'a> {
+    unsafe fn consume(&mut self, step: usize) {
+        debug_assert!(step <= self.cursor);
+
+        if step == 0 {
+            return
+        }
+
+        let remaining = self.cursor.saturating_sub(step);
+
+        if remaining != 0 {
+            let base = self.inner.as_mut_ptr() as *mut u8;
+            ptr::copy(base.offset(step as isize), base, remaining);
+        }
+
+        self.set_len(remaining)
+    }
+
+    unsafe fn read(&mut self, ptr: *mut u8, size: usize) {
+        debug_assert!(!ptr.is_null());
+
+        ptr::copy_nonoverlapping(self.as_ptr(), ptr, size);
+        self.consume(size);
+    }
+}
+
+impl<'a> PeekBuf for BufferRef<'a> {}
+
+impl<'a> ContBuf for BufferRef<'a> {
+    #[inline(always)]
+    fn as_read_slice(&self) -> &[u8] {
+        self.as_slice()
+    }
+
+    #[inline(always)]
+    fn as_read_slice_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+
+    #[inline(always)]
+    fn as_write_slice(&mut self) -> &mut [mem::MaybeUninit<u8>] {
+        //Caller may de-initialize these bytes
+        self.init = self.cursor;
+        &mut self.inner[self.cursor..]
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> std::io::Write for BufferRef<'a> {
+    #[inline(always)]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(self.write_slice(buf))
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Positions of `Ring`, captured by `Ring::save_state`.
+pub struct RingState {
+    ///Raw read position.
+    pub read: usize,
+    ///Raw write position.
+    pub cursor: usize,
+}
+
+///Circular version of `Buffer`
+///
+///Because `Buffer` is circular, it always has remaining bytes to write.
+///But care must be taken because without consuming already written bytes, it is easy to over-write
+///as ring buffer always has capacity.
+///
+///Writes behave as if bytes are written one by one, hence once more than `capacity` bytes are
+///written at once, only last `capacity` of them are kept.
+pub struct Ring<T: Sized> {
+    buffer: Buffer<T>,
+    read: usize,
+    overflow_hook: Option<fn(usize)>,
+}
+
+impl<S: Sized> Ring<S> {
+    #[inline]
+    ///Creates new instance
+    pub const fn new() -> Self {
+        unsafe {
+            Self::from_parts(Buffer::new(), 0)
+        }
+    }
+
+    #[inline]
+    ///Creates new instance from parts
+    pub const unsafe fn from_parts(buffer: Buffer<S>, read: usize) -> Self {
+        Self {
+            buffer,
+            read,
+            overflow_hook: None,
+        }
+    }
+
+    #[inline]
+    ///Creates new instance from parts, validating read position.
+    ///
+    ///Returns error if `read` is ahead of buffer's cursor or there are more than `capacity` bytes between them.
+    pub const fn try_from_parts(buffer: Buffer<S>, read: usize) -> Result<Self, PartsError> {
+        if read > buffer.cursor {
+            Err(PartsError::ReadOverflow)
+        } else if buffer.cursor - read > Buffer::<S>::capacity() {
+            Err(PartsError::LenOverflow)
+        } else {
+            Ok(unsafe {
+                Self::from_parts(buffer, read)
+            })
+        }
+    }
+
+    ///Creates new instance, writing every byte of `iter`, over-writing oldest bytes as it wraps.
+    ///
+    ///Resulting ring holds last `capacity` bytes of `iter`.
+    pub fn from_iter_overwriting<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut ring = Self::new();
+        for byte in iter {
+            ring.write_slice(&[byte]);
+        }
+        ring
+    }
+
+    #[inline]
+    ///Creates new instance from parts
+    pub const fn into_parts(self) -> (Buffer<S>, usize) {
+        //Ring doesn't keep track of initialized bytes
+        let mut buffer = self.buffer;
+        buffer.init = 0;
+        (buffer, self.read)
+    }
+
+    #[inline]
+    ///Returns raw read position, as stored in parts.
+    pub const fn read_pos(&self) -> usize {
+        self.read
+    }
+
+    #[inline]
+    ///Returns raw write position, as stored in parts.
+    pub const fn write_pos(&self) -> usize {
+        self.buffer.cursor
+    }
+
+    #[inline]
+    ///Returns current positions, which together with storage bytes describe ring's content.
+    pub const fn save_state(&self) -> RingState {
+        RingState {
+            read: self.read,
+            cursor: self.buffer.cursor,
+        }
+    }
+
+    #[inline]
+    ///Restores positions, previously returned by `save_state`.
+    ///
+    ///User must guarantee that storage contains bytes of the saved ring, e.g. restored via
+    ///`storage_mut`, and that state is valid, i.e. there are no more than `capacity` bytes between positions.
+    pub unsafe fn load_state(&mut self, state: RingState) {
+        debug_assert!(state.cursor.wrapping_sub(state.read) <= Buffer::<S>::capacity());
+        self.read = state.read;
+        self.buffer.cursor = state.cursor;
+    }
+
+    #[inline]
+    ///Returns whole underlying storage, regardless of positions.
+    pub fn storage(&self) -> &[mem::MaybeUninit<u8>] {
+        unsafe {
+            slice::from_raw_parts(self.buffer.as_ptr() as *const mem::MaybeUninit<u8>, Buffer::<S>::capacity())
+        }
+    }
+
+    #[inline]
+    ///Returns whole underlying storage as mutable, regardless of positions.
+    pub fn storage_mut(&mut self) -> &mut [mem::MaybeUninit<u8>] {
+        unsafe {
+            slice::from_raw_parts_mut(self.buffer.as_ptr() as *mut mem::MaybeUninit<u8>, Buffer::<S>::capacity())
+        }
+    }
+
+    #[inline]
+    ///Starts transaction, which allows to undo reads.
+    pub fn begin_transaction(&mut self) -> ReadTxn<'_, S> {
+        ReadTxn {
+            snapshot: self.snapshot(),
+            ring: self,
+            done: false,
+        }
+    }
+
+    #[inline]
+    ///Sets callback to be invoked whenever write over-writes unread bytes.
+    ///
+    ///Callback receives number of dropped bytes.
+    pub fn set_overflow_hook(&mut self, hook: fn(dropped: usize)) {
+        self.overflow_hook = Some(hook);
+    }
+
+    #[inline]
+    ///Returns mask, used to wrap raw positions into storage's index, i.e. `capacity - 1`.
+    pub const fn mask() -> usize {
+        Buffer::<S>::capacity() - 1
+    }
+
+    #[inline]
+    ///Wraps raw position, as stored in parts, into storage's index.
+    pub const fn mask_index(idx: usize) -> usize {
+        idx & Self::mask()
+    }
+
+    ///Returns number of available elements
+    pub const fn len(&self) -> usize {
+        self.buffer.cursor - self.read
+    }
+
+    ///Returns whether buffer is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.buffer.cursor == self.read
+    }
+
+    ///Returns whether buffer is full.
+    pub const fn is_full(&self) -> bool {
+        Buffer::<S>::capacity() == self.len()
+    }
+
+    #[inline]
+    ///Returns number of bytes that can be written before oldest bytes are over-written.
+    ///
+    ///Unlike `WriteBuf::remaining`, which is always `capacity` as ring accepts any write by
+    ///over-writing, it treats ring as bounded queue.
+    pub const fn free(&self) -> usize {
+        Buffer::<S>::capacity() - self.len()
+    }
+
+    #[inline]
+    ///Returns readable bytes as pair of slices in logical order.
+    ///
+    ///Second slice is non-empty only when readable bytes wrap around the end of buffer.
+    pub fn as_slices(&self) -> (&[u8], &[u8]) {
+        let idx = Self::mask_index(self.read);
+        let len = self.len();
+        let head = cmp::min(Buffer::<S>::capacity() - idx, len);
+
+        unsafe {
+            (slice::from_raw_parts(self.buffer.as_ptr().offset(idx as isize), head), slice::from_raw_parts(self.buffer.as_ptr(), len - head))
+        }
+    }
+
+    #[inline]
+    ///Returns iterator, which yields readable bytes in logical order, repeating them indefinitely.
+    ///
+    ///If ring is empty, iterator yields nothing.
+    pub fn cycle_bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..self.len()).cycle().map(move |idx| self[idx])
+    }
+
+    ///Consumes up to `step` oldest bytes, overwriting them with zeroes in storage.
+    ///
+    ///Prevents sensitive data from lingering in memory after it is consumed.
+    pub fn consume_zeroed(&mut self, step: usize) {
+        let step = cmp::min(step, self.len());
+        let idx = Self::mask_index(self.read);
+        let head = cmp::min(Buffer::<S>::capacity() - idx, step);
+        let ptr = self.buffer.as_ptr() as *mut u8;
+
+        unsafe {
+            ptr::write_bytes(ptr.offset(idx as isize), 0, head);
+            ptr::write_bytes(ptr, 0, step - head);
+            self.consume(step);
+        }
+    }
+
+    #[inline]
+    ///Discards up to `n` oldest readable bytes, returning number of dropped bytes.
+    pub fn drop_front(&mut self, n: usize) -> usize {
+        let n = cmp::min(n, self.len());
+        self.read = self.read.wrapping_add(n);
+        n
+    }
+
+    ///Copies `N` readable bytes, starting at logical offset `start`, into array.
+    ///
+    ///Returns `None` if there are not enough readable bytes.
+    pub fn slice_to_array<const N: usize>(&self, start: usize) -> Option<[u8; N]> {
+        match start.checked_add(N) {
+            Some(end) if end <= self.len() => (),
+            _ => return None,
+        }
+
+        let mut result = [0u8; N];
+        let idx = Self::mask_index(self.read.wrapping_add(start));
+        let head = cmp::min(Buffer::<S>::capacity() - idx, N);
+
+        unsafe {
+            ptr::copy_nonoverlapping(self.buffer.as_ptr().offset(idx as isize), result.as_mut_ptr(), head);
+            ptr::copy_nonoverlapping(self.buffer.as_ptr(), result.as_mut_ptr().offset(head as isize), N - head);
+        }
+
+        Some(result)
+    }
+
+    #[inline]
+    ///Returns whether readable bytes wrap around the end of storage.
+    pub fn is_wrapped(&self) -> bool {
+        Self::mask_index(self.read) + self.len() > Buffer::<S>::capacity()
+    }
+
+    #[inline]
+    ///Writes value by performing bit copy, over-writing oldest bytes if necessary.
+    ///
+    ///Returns error if value is larger than `capacity`, as it would over-write itself.
+    pub fn write_value_checked<T: Copy + Sized>(&mut self, val: &T) -> Result<(), CapacityError> {
+        match mem::size_of::<T>() > Buffer::<S>::capacity() {
+            true => Err(CapacityError),
+            false => {
+                self.write_value(val);
+                Ok(())
+            }
+        }
+    }
+
+    #[inline]
+    ///Returns readable bytes as `str`, if they are contiguous and valid UTF-8.
+    pub fn as_str(&self) -> Option<&str> {
+        match self.as_slices() {
+            (head, []) => core::str::from_utf8(head).ok(),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    //Returns start and lengths of both spans of free space, in order of writing
+    fn free_spans(&self) -> (usize, usize, usize) {
+        let idx = Self::mask_index(self.buffer.cursor);
+        let free = Buffer::<S>::capacity() - self.len();
+        let head = cmp::min(Buffer::<S>::capacity() - idx, free);
+
+        (idx, head, free - head)
+    }
+
+    #[inline]
+    ///Returns number of bytes that can be written in single contiguous span.
+    ///
+    ///It is the span from write cursor until either end of buffer or unread bytes, whichever
+    ///comes first, i.e. it never includes space that would over-write unread bytes.
+    pub fn writable_contiguous(&self) -> usize {
+        self.free_spans().1
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    ///Returns readable bytes as `IoSlice`s for vectored write.
+    ///
+    ///Second slice is empty unless readable bytes wrap.
+    pub fn as_read_iovecs(&self) -> [std::io::IoSlice<'_>; 2] {
+        let (head, tail) = self.as_slices();
+        [std::io::IoSlice::new(head), std::io::IoSlice::new(tail)]
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    ///Returns free space as `IoSliceMut`s for vectored read.
+    ///
+    ///Second slice is empty unless free space wraps.
+    ///After filling them, use `WriteBuf::advance` to mark bytes as written.
+    ///
+    ///Free space is zeroed beforehand, as it may be uninitialized.
+    pub fn as_write_iovecs(&mut self) -> [std::io::IoSliceMut<'_>; 2] {
+        let (idx, head, tail) = self.free_spans();
+        let ptr = self.buffer.as_ptr() as *mut u8;
+
+        unsafe {
+            ptr::write_bytes(ptr.offset(idx as isize), 0, head);
+            ptr::write_bytes(ptr, 0, tail);
+
+            [
+                std::io::IoSliceMut::new(slice::from_raw_parts_mut(ptr.offset(idx as isize), head)),
+                std::io::IoSliceMut::new(slice::from_raw_parts_mut(ptr, tail)),
+            ]
+        }
+    }
+}
+
+impl<S: Sized> ops::Index<usize> for Ring<S> {
+    type Output = u8;
+
+    #[inline(always)]
+    fn index(&self, mut index: usize) -> &Self::Output {
+        debug_assert!(index < self.len());
+        index = Self::mask_index(self.read.wrapping_add(index));
+        unsafe {
+            &*self.buffer.as_ptr().offset(index as isize)
+        }
+    }
+}
+
+impl<S: Sized> ops::IndexMut<usize> for Ring<S> {
+    #[inline(always)]
+    fn index_mut(&mut self, mut index: usize) -> &mut Self::Output {
+        debug_assert!(index < self.len());
+        index = Self::mask_index(self.read.wrapping_add(index));
+        unsafe {
+            &mut *(self.buffer.as_ptr().offset(index as isize) as *mut _)
+        }
+    }
+}
+
+impl<S: Sized> Buf for Ring<S> {
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        Buffer::<S>::capacity()
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+
+    fn get_range(&self, range: ops::Range<usize>) -> Option<&[u8]> {
+        if range.start > range.end || range.end > self.len() {
+            return None;
+        }
+
+        let idx = Self::mask_index(self.read.wrapping_add(range.start));
+        let len = range.end - range.start;
+        if len == 0 {
+            Some(&[])
+        } else if idx + len <= Buffer::<S>::capacity() {
+            Some(unsafe {
+                slice::from_raw_parts(self.buffer.as_ptr().offset(idx as isize), len)
+            })
+        } else {
+            None
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_vec(&self) -> crate::alloc::Vec<u8> {
+        let (head, tail) = self.as_slices();
+        let mut result = crate::alloc::Vec::with_capacity(head.len() + tail.len());
+        result.extend_from_slice(head);
+        result.extend_from_slice(tail);
+        result
+    }
+}
+
+impl<S: Sized> ReadBuf for Ring<S> {
+    #[inline(always)]
+    fn available(&self) -> usize {
+        Self::len(self)
+    }
+
+    #[inline(always)]
+    fn read_offset(&self) -> usize {
+        Self::mask_index(self.read)
+    }
+
+    #[inline]
+    unsafe fn consume(&mut self, step: usize) {
+        self.read = self.read.wrapping_add(step);
+    }
+
+    unsafe fn read(&mut self, ptr: *mut u8, mut size: usize) {
         debug_assert!(!ptr.is_null());
         debug_assert!((Buffer::<S>::capacity() & (Buffer::<S>::capacity() - 1)) == 0, "Capacity is not power of 2");
+        let idx = Self::mask_index(self.read);
+        let read_span = cmp::min(Buffer::<S>::capacity() - idx, size);
+
+        ptr::copy_nonoverlapping(self.buffer.as_ptr().offset(idx as isize), ptr, read_span);
+        self.consume(read_span);
+        size -= read_span;
+
+        if size > 0 {
+            let avail_size = cmp::min(size, self.available());
+            if avail_size > 0 {
+                ptr::copy_nonoverlapping(self.buffer.as_ptr(), ptr.offset(read_span as isize), avail_size);
+                self.consume(avail_size);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn write_to<W: std::io::Write>(&mut self, w: &mut W) -> std::io::Result<usize> {
+        let mut written = 0;
+
+        loop {
+            let head = self.as_slices().0;
+            if head.is_empty() {
+                break;
+            }
+
+            let accepted = crate::write_step(w, head, written)?;
+            if accepted == 0 {
+                break;
+            }
+
+            unsafe {
+                self.consume(accepted);
+            }
+            written += accepted;
+        }
 
-        let cursor = Self::mask_idx(self.buffer.cursor);
-        let mut write_span = cmp::min(Buffer::<S>::capacity() - cursor, size);
+        Ok(written)
+    }
+}
+
+impl<S: Sized> PeekBuf for Ring<S> {}
+
+impl<S: Sized> RewindBuf for Ring<S> {
+    #[inline(always)]
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            read: self.read,
+        }
+    }
+
+    #[inline]
+    fn restore(&mut self, snapshot: Snapshot) -> bool {
+        //Both over-written bytes and position ahead of write cursor result in span over capacity
+        if self.buffer.cursor.wrapping_sub(snapshot.read) > Buffer::<S>::capacity() {
+            return false;
+        }
+
+        self.read = snapshot.read;
+        true
+    }
+}
+
+///Transaction over `Ring` reads, created by `Ring::begin_transaction`.
+///
+///Gives access to ring, restoring read position on `rollback` or drop, unless `commit` is called.
+pub struct ReadTxn<'a, S: Sized> {
+    ring: &'a mut Ring<S>,
+    snapshot: Snapshot,
+    done: bool,
+}
+
+impl<'a, S: Sized> ReadTxn<'a, S> {
+    #[inline]
+    ///Keeps all reads, performed within transaction.
+    pub fn commit(mut self) {
+        self.done = true;
+    }
+
+    #[inline]
+    ///Restores read position, returning whether it is successful.
+    ///
+    ///Fails if bytes read within transaction were over-written since.
+    pub fn rollback(mut self) -> bool {
+        self.done = true;
+        self.ring.restore(self.snapshot)
+    }
+}
+
+impl<'a, S: Sized> ops::Deref for ReadTxn<'a, S> {
+    type Target = Ring<S>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.ring
+    }
+}
+
+impl<'a, S: Sized> ops::DerefMut for ReadTxn<'a, S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.ring
+    }
+}
+
+impl<'a, S: Sized> Drop for ReadTxn<'a, S> {
+    #[inline]
+    fn drop(&mut self) {
+        if !self.done {
+            self.ring.restore(self.snapshot);
+        }
+    }
+}
+
+impl<S: Sized> WriteBuf for Ring<S> {
+    #[inline(always)]
+    fn remaining(&self) -> usize {
+        Buffer::<S>::capacity()
+    }
+
+    #[inline(always)]
+    unsafe fn advance(&mut self, step: usize) {
+        self.buffer.cursor = self.buffer.cursor.wrapping_add(step);
+
+        let read_span = self.buffer.cursor - self.read;
+        if read_span > Buffer::<S>::capacity() {
+            //consume over-written bytes
+            let dropped = read_span - Buffer::<S>::capacity();
+            self.consume(dropped);
+
+            if let Some(hook) = self.overflow_hook {
+                hook(dropped);
+            }
+        }
+
+        debug_assert!(self.len() <= Buffer::<S>::capacity(), "Length exceeds capacity");
+    }
+
+    unsafe fn write(&mut self, ptr: *const u8, size: usize) {
+        debug_assert!(!ptr.is_null());
+        debug_assert!((Buffer::<S>::capacity() & (Buffer::<S>::capacity() - 1)) == 0, "Capacity is not power of 2");
+
+        //Only last `capacity` bytes survive, everything before would be over-written anyway
+        let skip = size.saturating_sub(Buffer::<S>::capacity());
+        let write_len = size - skip;
+        let ptr = ptr.offset(skip as isize);
+
+        if skip > 0 {
+            //Whole content is over-written, while skipped bytes are never stored
+            let dropped = self.len();
+            self.buffer.cursor = self.buffer.cursor.wrapping_add(skip);
+            self.read = self.buffer.cursor;
+
+            if dropped > 0 {
+                if let Some(hook) = self.overflow_hook {
+                    hook(dropped);
+                }
+            }
+        }
+
+        let cursor = Self::mask_index(self.buffer.cursor);
+        let write_span = cmp::min(Buffer::<S>::capacity() - cursor, write_len);
 
         ptr::copy_nonoverlapping(ptr, self.buffer.as_ptr().offset(cursor as isize) as *mut u8, write_span);
-        size -= write_span;
+        ptr::copy_nonoverlapping(ptr.offset(write_span as isize), self.buffer.as_ptr() as *mut u8, write_len - write_span);
+
+        self.advance(write_len);
+    }
+
+    #[inline]
+    ///Writes supplied slice, over-writing oldest bytes, returning number of bytes kept in buffer.
+    ///
+    ///If `bytes` is longer than capacity, only its last `capacity` bytes are kept.
+    fn write_slice(&mut self, bytes: &[u8]) -> usize {
+        if !bytes.is_empty() {
+            unsafe {
+                self.write(bytes.as_ptr(), bytes.len());
+            }
+        }
+
+        cmp::min(bytes.len(), Buffer::<S>::capacity())
+    }
+
+    ///Writes supplied slice in reverse order, over-writing oldest bytes, returning number of bytes kept in buffer.
+    ///
+    ///Same as `write_slice`, keeps last `capacity` written bytes, which are first bytes of `bytes`.
+    fn write_slice_rev(&mut self, bytes: &[u8]) -> usize {
+        let write_len = cmp::min(bytes.len(), Buffer::<S>::capacity());
 
-        while size > 0 {
-            let avail_size = cmp::min(size, Buffer::<S>::capacity());
+        for byte in bytes[..write_len].iter().rev() {
+            unsafe {
+                self.write(byte, 1);
+            }
+        }
+
+        write_len
+    }
 
-            ptr::copy_nonoverlapping(ptr.offset(write_span as isize), self.buffer.as_ptr() as *mut u8, avail_size);
-            size -= avail_size;
-            write_span += avail_size;
+    #[cfg(feature = "std")]
+    ///Reads into contiguous free space, never over-writing unread bytes.
+    fn read_from<R: std::io::Read>(&mut self, r: &mut R) -> std::io::Result<usize> {
+        let (idx, head, _) = self.free_spans();
+        let spare = unsafe {
+            let ptr = (self.buffer.as_ptr() as *mut u8).offset(idx as isize);
+            ptr::write_bytes(ptr, 0, head);
+            slice::from_raw_parts_mut(ptr, head)
+        };
+
+        let size = cmp::min(r.read(spare)?, spare.len());
+        unsafe {
+            self.advance(size);
         }
+        Ok(size)
+    }
+}
 
-        self.advance(write_span);
+impl<S: Sized> fmt::Write for Ring<S> {
+    #[inline]
+    ///Writes string's bytes, always succeeding.
+    ///
+    ///As ring buffer always has capacity, old bytes may be overwritten by new ones, making it
+    ///possible to keep only the most recent output.
+    ///If `text` is longer than capacity, only its last `capacity` bytes are written.
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        self.write_slice(text.as_bytes());
+        Ok(())
     }
 }
+
+///Integer, which can be converted to and from particular byte order.
+pub trait Endian: Copy + Sized {
+    ///Converts value from host to little endian byte order.
+    fn to_le(self) -> Self;
+    ///Converts value from host to big endian byte order.
+    fn to_be(self) -> Self;
+    ///Converts value from little endian to host byte order.
+    fn from_le(self) -> Self;
+    ///Converts value from big endian to host byte order.
+    fn from_be(self) -> Self;
+}
+
+macro_rules! impl_endian {
+    ($($typ:ident),*) => {$(
+        impl Endian for $typ {
+            #[inline(always)]
+            fn to_le(self) -> Self {
+                $typ::to_le(self)
+            }
+
+            #[inline(always)]
+            fn to_be(self) -> Self {
+                $typ::to_be(self)
+            }
+
+            #[inline(always)]
+            fn from_le(self) -> Self {
+                $typ::from_le(self)
+            }
+
+            #[inline(always)]
+            fn from_be(self) -> Self {
+                $typ::from_be(self)
+            }
+        }
+    )*};
+}
+
+impl_endian!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+
+macro_rules! impl_endian_view {
+    ($name:ident, $to:ident, $from:ident) => {
+        impl<W: ops::DerefMut> $name<W> where W::Target: WriteBuf {
+            #[inline]
+            ///Writes value in wrapper's byte order, returning number of bytes written.
+            ///
+            ///If value cannot fit, does nothing
+            pub fn write_value<T: Endian>(&mut self, val: &T) -> usize {
+                self.0.write_value(&val.$to())
+            }
+        }
+
+        impl<W: ops::DerefMut> $name<W> where W::Target: ReadBuf {
+            #[inline]
+            ///Reads value in wrapper's byte order.
+            ///
+            ///If not enough bytes, does nothing, returning 0
+            pub fn read_value<T: Endian>(&mut self, val: &mut mem::MaybeUninit<T>) -> usize {
+                let size = self.0.read_value(val);
+                if size != 0 {
+                    unsafe {
+                        val.as_mut_ptr().write(val.as_ptr().read().$from());
+                    }
+                }
+                size
+            }
+        }
+    };
+}
+
+///Little endian view over buffer.
+///
+///Typed values written and read through it always use little endian byte order, regardless of
+///host.
+///
+///```rust
+///use baffa::StaticBuffer;
+///use baffa::stack::Le;
+///
+///let mut buffer = StaticBuffer::<u16>::new();
+///assert_eq!(Le(&mut buffer).write_value(&0x1234u16), 2);
+///assert_eq!(buffer.as_slice(), [0x34, 0x12]);
+///```
+pub struct Le<W>(pub W);
+
+impl_endian_view!(Le, to_le, from_le);
+
+///Big endian view over buffer.
+///
+///Typed values written and read through it always use big endian byte order, regardless of host.
+///
+///```rust
+///use baffa::StaticBuffer;
+///use baffa::stack::Be;
+///
+///let mut buffer = StaticBuffer::<u16>::new();
+///assert_eq!(Be(&mut buffer).write_value(&0x1234u16), 2);
+///assert_eq!(buffer.as_slice(), [0x12, 0x34]);
+///```
+pub struct Be<W>(pub W);
+
+impl_endian_view!(Be, to_be, from_be);
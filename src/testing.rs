@@ -0,0 +1,52 @@
+//!Helpers to verify buffer implementations
+
+use crate::{ReadBuf, WriteBuf};
+
+///Verifies that ring created by `make` follows semantics of `stack::Ring`, panicking otherwise.
+///
+///Checks empty state, filling up to `cap`, overwriting oldest bytes when full and reads that
+///wrap around the end of storage.
+pub fn ring_conformance<R: ReadBuf + WriteBuf, F: Fn() -> R>(make: F, cap: usize) {
+    assert!(cap >= 2, "Capacity is too small to verify wrapping");
+
+    let mut ring = make();
+    let mut byte = [0u8; 1];
+    assert_eq!(ring.capacity(), cap);
+    assert_eq!(ring.len(), 0);
+    assert_eq!(ring.available(), 0);
+    assert_eq!(ring.read_slice(&mut byte), 0);
+
+    for idx in 0..cap {
+        assert_eq!(ring.write_slice(&[idx as u8]), 1);
+        assert_eq!(ring.len(), idx + 1);
+    }
+    for idx in 0..cap {
+        assert_eq!(ring[idx], idx as u8);
+    }
+
+    //oldest byte is over-written
+    assert_eq!(ring.write_slice(&[cap as u8]), 1);
+    assert_eq!(ring.len(), cap);
+    for idx in 0..cap {
+        assert_eq!(ring[idx], (idx + 1) as u8);
+    }
+
+    //read cursor is at second byte of storage, so draining it wraps
+    for idx in 0..cap - 2 {
+        assert_eq!(ring.read_slice(&mut byte), 1);
+        assert_eq!(byte[0], (idx + 1) as u8);
+    }
+    let mut tail = [0u8; 2];
+    assert_eq!(ring.read_slice(&mut tail), 2);
+    assert_eq!(tail, [(cap - 1) as u8, cap as u8]);
+
+    assert_eq!(ring.len(), 0);
+    assert_eq!(ring.available(), 0);
+    assert_eq!(ring.read_slice(&mut byte), 0);
+
+    //empty ring is reusable
+    assert_eq!(ring.write_slice(&[1, 2]), 2);
+    assert_eq!(ring.read_slice(&mut tail), 2);
+    assert_eq!(tail, [1, 2]);
+    assert_eq!(ring.len(), 0);
+}
@@ -0,0 +1,116 @@
+use baffa::{StaticBuffer, Buf, ReadBuf, ReadBufExt, WriteBuf};
+use baffa::adapter::{ChunkLimit, Framed, Tee};
+
+#[test]
+fn test_framed() {
+    let mut framed = Framed::new(StaticBuffer::<[u8; 16]>::new());
+    assert_eq!(framed.remaining(), 12);
+
+    assert_eq!(framed.write_message(b"hello"), 9);
+    assert_eq!(framed.remaining(), 3);
+    assert_eq!(framed.write_message(b"hell"), 0);
+    assert_eq!(framed.write_message(b"hey"), 7);
+    assert_eq!(framed.remaining(), 0);
+    assert_eq!(framed.write_message(b""), 0);
+
+    let mut buffer = framed.into_inner();
+    assert_eq!(buffer.take_array::<4>().map(u32::from_be_bytes), Some(5));
+    assert_eq!(buffer.take_array::<5>(), Some(*b"hello"));
+    assert_eq!(buffer.take_array::<4>().map(u32::from_be_bytes), Some(3));
+    assert_eq!(buffer.take_array::<3>(), Some(*b"hey"));
+    assert_eq!(buffer.len(), 0);
+}
+
+#[test]
+fn test_has_frame_u32() {
+    let mut framed = Framed::new(StaticBuffer::<[u8; 16]>::new());
+    assert!(!framed.get_ref().has_frame_u32());
+
+    assert_eq!(framed.write_message(b"hello"), 9);
+    let mut buffer = framed.into_inner();
+    assert!(buffer.has_frame_u32());
+    assert_eq!(buffer.len(), 9);
+
+    //not enough prefix
+    let mut partial = StaticBuffer::<[u8; 16]>::new();
+    partial.write_slice(&buffer.as_slice()[..3]);
+    assert!(!partial.has_frame_u32());
+
+    //prefix without whole payload
+    partial.write_slice(&buffer.as_slice()[3..8]);
+    assert!(!partial.has_frame_u32());
+    partial.write_slice(&buffer.as_slice()[8..]);
+    assert!(partial.has_frame_u32());
+
+    //empty payload
+    buffer.consume_all();
+    buffer.write_slice(&[0, 0, 0, 0]);
+    assert!(buffer.has_frame_u32());
+}
+
+#[test]
+fn test_tee() {
+    let mut reader = StaticBuffer::<u64>::new();
+    assert_eq!(reader.write_slice(&[1, 2, 3, 4, 5, 6, 7]), 7);
+
+    let mut tee = Tee::new(reader, StaticBuffer::<[u8; 6]>::new());
+    assert_eq!(tee.take_array::<2>(), Some([1, 2]));
+    assert_eq!(tee.sink().as_slice(), [1, 2]);
+
+    let mut res = [0u8; 2];
+    assert_eq!(tee.read_slice(&mut res), 2);
+    assert_eq!(res, [3, 4]);
+    assert_eq!(tee.sink().as_slice(), [1, 2, 3, 4]);
+
+    unsafe {
+        tee.consume(3);
+    }
+    assert_eq!(tee.available(), 0);
+
+    let (reader, sink) = tee.into_inner();
+    assert_eq!(reader.len(), 0);
+    //sink capacity limits mirroring
+    assert_eq!(sink.as_slice(), [1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn test_chunk_limit() {
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    buffer.write_slice(b"abcdefg");
+
+    let mut reader = ChunkLimit::new(buffer, 3);
+    let mut chunk = [0u8; 8];
+    assert_eq!(reader.available(), 3);
+    assert_eq!(reader.get_range(1..3), Some(&b"bc"[..]));
+    assert_eq!(reader.read_slice(&mut chunk), 3);
+    assert_eq!(&chunk[..3], b"abc");
+    assert_eq!(reader.read_slice(&mut chunk[..2]), 2);
+    assert_eq!(&chunk[..2], b"de");
+    assert_eq!(reader.take_array::<3>(), None);
+    assert_eq!(reader.read_slice(&mut chunk), 2);
+    assert_eq!(&chunk[..2], b"fg");
+    assert_eq!(reader.available(), 0);
+    assert_eq!(reader.into_inner().len(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_strict_write() {
+    use std::io::{self, Write};
+    use baffa::adapter::StrictWrite;
+
+    let mut buffer = StaticBuffer::<u32>::new();
+    buffer.write_slice(b"ab");
+
+    let mut writer = StrictWrite::new(&mut buffer);
+    let error = writer.write(b"cde").unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+    assert_eq!(writer.write(b"cd").expect("to write"), 2);
+    assert_eq!(writer.write(b"").expect("to write"), 0);
+    assert_eq!(writer.write_all(b"e").unwrap_err().kind(), io::ErrorKind::WriteZero);
+    assert_eq!(buffer.as_slice(), b"abcd");
+
+    let mut buffer = StaticBuffer::<u32>::new();
+    assert!(StrictWrite::new(&mut buffer).write(b"abcde").is_err());
+    assert_eq!(buffer.len(), 0);
+}
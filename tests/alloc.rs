@@ -0,0 +1,68 @@
+#![cfg(feature = "alloc")]
+
+use baffa::DynBuf;
+
+#[test]
+fn test_vec_dyn_buf() {
+    let mut buffer = Vec::<u8>::new();
+    assert!(DynBuf::try_reserve(&mut buffer, 16).is_ok());
+    assert!(buffer.capacity() >= 16);
+
+    DynBuf::reserve(&mut buffer, 32);
+    assert!(buffer.capacity() >= 32);
+
+    buffer.extend_from_slice(&[1, 2, 3]);
+    let capacity = buffer.capacity();
+    DynBuf::shrink(&mut buffer, capacity - 2);
+    assert_eq!(buffer, [1, 2]);
+
+    DynBuf::shrink(&mut buffer, usize::MAX);
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn test_to_vec() {
+    use baffa::{Buf, StaticBuffer, WriteBuf, ReadBuf};
+
+    let mut buffer = StaticBuffer::<[u8; 6]>::new();
+    buffer.write_slice(b"abc");
+    assert_eq!(buffer.to_vec(), b"abc");
+
+    let mut ring = StaticBuffer::<u32>::new().into_circular();
+    ring.write_slice(b"abc");
+    let mut res = [0u8; 2];
+    assert_eq!(ring.read_slice(&mut res), 2);
+    ring.write_slice(b"def");
+    assert_eq!(ring.as_slices(), (&b"cd"[..], &b"ef"[..]));
+    assert_eq!(ring.to_vec(), b"cdef");
+}
+
+#[test]
+fn test_vec_write_all_grow() {
+    use baffa::GrowBuf;
+
+    let mut buffer = Vec::<u8>::with_capacity(2);
+    buffer.write_all_grow(b"abc");
+    buffer.write_all_grow(b"");
+    buffer.write_all_grow(&[b'd'; 64]);
+    assert_eq!(buffer.len(), 67);
+    assert_eq!(&buffer[..4], b"abcd");
+    assert!(buffer[3..].iter().all(|byte| *byte == b'd'));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_vec_read_from() {
+    use baffa::WriteBuf;
+
+    let data = [7u8; 200];
+    let mut src = &data[..];
+    let mut buffer = Vec::with_capacity(150);
+    buffer.extend_from_slice(b"ab");
+
+    let spare = buffer.capacity() - buffer.len();
+    assert_eq!(buffer.read_from(&mut src).expect("to read"), spare);
+    assert_eq!(&buffer[..2], b"ab");
+    assert!(buffer[2..].iter().all(|byte| *byte == 7));
+    assert_eq!(buffer.read_from(&mut src).expect("to read"), 0);
+}
@@ -0,0 +1,238 @@
+use baffa::{StaticBuffer, ReadBuf, WriteBuf, WriteBufExt};
+use baffa::codec::Serializer;
+
+#[derive(Clone, Copy)]
+struct Record {
+    id: u32,
+    kind: u8,
+    flags: u16,
+    value: u64,
+    tag: [u8; 3],
+}
+
+const RECORD_SIZE: usize = 4 + 1 + 2 + 8 + 3;
+
+fn serialize<W: baffa::WriteBuf + baffa::ContBuf>(buffer: &mut W, record: &Record) -> usize {
+    let mut ser = Serializer::new(buffer, RECORD_SIZE).expect("to fit");
+    //RECORD_SIZE covers all fields
+    unsafe {
+        ser.value(&record.id).value(&record.kind).value(&record.flags).value(&record.value).slice(&record.tag);
+    }
+    assert_eq!(ser.remaining(), 0);
+    ser.finish()
+}
+
+#[test]
+fn test_serializer_record() {
+    let record = Record {
+        id: 1,
+        kind: 2,
+        flags: 3,
+        value: 4,
+        tag: *b"tag",
+    };
+
+    let mut buffer = StaticBuffer::<[u8; 32]>::new();
+    assert_eq!(serialize(&mut buffer, &record), RECORD_SIZE);
+
+    let mut expected = StaticBuffer::<[u8; 32]>::new();
+    expected.write_value(&record.id);
+    expected.write_value(&record.kind);
+    expected.write_value(&record.flags);
+    expected.write_value(&record.value);
+    expected.write_value(&record.tag);
+    assert_eq!(buffer.as_slice(), expected.as_slice());
+
+    let mut buffer = StaticBuffer::<[u8; 16]>::new();
+    assert!(Serializer::new(&mut buffer, RECORD_SIZE).is_none());
+
+    //dropped serializer doesn't commit anything
+    {
+        let mut ser = Serializer::new(&mut buffer, 4).expect("to fit");
+        unsafe {
+            ser.value(&1u32);
+        }
+    }
+    assert_eq!(buffer.len(), 0);
+}
+
+#[test]
+fn test_serializer_many_records() {
+    const COUNT: usize = 204;
+
+    let mut fast = StaticBuffer::<[u8; COUNT * RECORD_SIZE]>::new();
+    let mut slow = StaticBuffer::<[u8; COUNT * RECORD_SIZE]>::new();
+
+    for idx in 0..COUNT {
+        let record = Record {
+            id: idx as u32,
+            kind: idx as u8,
+            flags: !(idx as u16),
+            value: (idx as u64) << 32,
+            tag: [idx as u8; 3],
+        };
+
+        assert_eq!(serialize(&mut fast, &record), RECORD_SIZE);
+
+        assert_eq!(slow.write_value(&record.id), 4);
+        assert_eq!(slow.write_value(&record.kind), 1);
+        assert_eq!(slow.write_value(&record.flags), 2);
+        assert_eq!(slow.write_value(&record.value), 8);
+        assert_eq!(slow.write_value(&record.tag), 3);
+    }
+
+    assert_eq!(fast.remaining(), 0);
+    assert_eq!(fast.as_slice(), slow.as_slice());
+}
+
+#[test]
+fn test_encode_decode() {
+    use baffa::codec::{Encode, Decode};
+
+    let value = (1u8, -2i16, 0x01020304u32, -4i64, u128::MAX);
+
+    let mut buffer = StaticBuffer::<[u8; 32]>::new();
+    assert_eq!(value.0.encode(&mut buffer), 1);
+    assert_eq!(value.1.encode(&mut buffer), 2);
+    assert_eq!(value.2.encode(&mut buffer), 4);
+    assert_eq!(value.3.encode(&mut buffer), 8);
+    assert_eq!(value.4.encode(&mut buffer), 16);
+    assert_eq!(&buffer.as_slice()[3..7], [4, 3, 2, 1]);
+    assert_eq!(1u16.encode(&mut buffer), 0);
+
+    let decoded = (
+        u8::decode(&mut buffer).unwrap(),
+        i16::decode(&mut buffer).unwrap(),
+        u32::decode(&mut buffer).unwrap(),
+        i64::decode(&mut buffer).unwrap(),
+        u128::decode(&mut buffer).unwrap(),
+    );
+    assert_eq!(decoded, value);
+    assert_eq!(buffer.len(), 0);
+
+    assert_eq!(1u8.encode(&mut buffer), 1);
+    assert_eq!(u16::decode(&mut buffer), None);
+    assert_eq!(buffer.len(), 1);
+}
+
+#[test]
+fn test_builder() {
+    use baffa::CapacityError;
+    use baffa::codec::Builder;
+
+    let mut buffer = StaticBuffer::<[u8; 16]>::new();
+    let written = Builder::new(&mut buffer).u8(1).u16_le(0x0203).u32_be(0x04050607).bytes(b"ab").finish();
+    assert_eq!(written, Ok(9));
+    assert_eq!(buffer.as_slice(), [1, 3, 2, 4, 5, 6, 7, b'a', b'b']);
+
+    let result = Builder::new(&mut buffer).u32_le(1).u64_be(2).u8(3).finish();
+    assert_eq!(result, Err(CapacityError));
+    //fields after failure are not written
+    assert_eq!(buffer.len(), 13);
+}
+
+#[test]
+fn test_parser() {
+    use baffa::codec::{Incomplete, Parser};
+
+    fn parse<R: ReadBuf>(parser: &mut Parser<'_, R>) -> Result<(u8, u16, u32, [u8; 2]), Incomplete> {
+        let kind = parser.u8()?;
+        let id = parser.u16_le()?;
+        let len = parser.u32_be()?;
+        let mut tag = [0u8; 2];
+        parser.bytes(&mut tag)?;
+        Ok((kind, id, len, tag))
+    }
+
+    let mut buffer = StaticBuffer::<[u8; 16]>::new();
+    buffer.write_slice(&[1, 3, 2, 4, 5, 6, 7, b'a', b'b', 9]);
+
+    let mut parser = Parser::new(&mut buffer);
+    assert_eq!(parse(&mut parser), Ok((1, 0x0203, 0x04050607, *b"ab")));
+    assert_eq!(parser.remaining(), 1);
+    assert_eq!(parser.finish(), 9);
+    assert_eq!(buffer.as_slice(), [9]);
+}
+
+#[test]
+fn test_parser_incomplete() {
+    use baffa::codec::{Incomplete, Parser};
+
+    let mut buffer = StaticBuffer::<[u8; 16]>::new().into_circular();
+    buffer.write_slice(&[1, 3, 2, 4, 5]);
+
+    {
+        let mut parser = Parser::new(&mut buffer);
+        assert_eq!(parser.u8(), Ok(1));
+        assert_eq!(parser.u16_be(), Ok(0x0302));
+        assert_eq!(parser.u32_le(), Err(Incomplete));
+    }
+    assert_eq!(buffer.len(), 5);
+    assert_eq!(buffer.as_slices(), (&[1, 3, 2, 4, 5][..], &[][..]));
+}
+
+//Writes LEB128 encoding of `value`
+fn write_leb128<W: WriteBuf>(buffer: &mut W, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buffer.write_slice(&[byte]);
+            break;
+        }
+        buffer.write_slice(&[byte | 0x80]);
+    }
+}
+
+#[test]
+fn test_encoded_len() {
+    use baffa::codec::{Encode, encoded_len_value, rle_len, varint_len};
+
+    let mut buffer = StaticBuffer::<[u8; 32]>::new();
+    assert_eq!(encoded_len_value::<u32>(), buffer.write_value(&1u32));
+    assert_eq!(encoded_len_value::<(u8, u64)>(), buffer.write_value(&(1u8, 2u64)));
+    assert_eq!(encoded_len_value::<u8>(), 1u8.encode(&mut buffer));
+    assert_eq!(encoded_len_value::<i16>(), (-1i16).encode(&mut buffer));
+    assert_eq!(encoded_len_value::<u64>(), 1u64.encode(&mut buffer));
+
+    let mut buffer = StaticBuffer::<[u8; 32]>::new();
+    assert_eq!(encoded_len_value::<u128>(), u128::MAX.encode(&mut buffer));
+
+    for data in [&[][..], &[1, 1, 2][..], &[1, 2, 3, 3, 3, 4][..], &[5; 300][..], &[5; 600][..]].iter() {
+        let mut buffer = StaticBuffer::<[u8; 32]>::new();
+        assert_eq!(buffer.write_rle(data), data.len());
+        assert_eq!(rle_len(data), buffer.len());
+    }
+
+    for value in [0u64, 1, 127, 128, 300, 16383, 16384, u32::MAX as u64, 1 << 56, u64::MAX].iter() {
+        let mut buffer = StaticBuffer::<[u8; 16]>::new();
+        write_leb128(&mut buffer, *value);
+        assert_eq!(varint_len(*value), buffer.len());
+    }
+
+    //prefix length matches bytes consumed by reader
+    let mut out = [0u8; 512];
+    for len in [0usize, 1, 127, 128, 300].iter() {
+        let mut buffer = StaticBuffer::<[u8; 512]>::new();
+        write_leb128(&mut buffer, *len as u64);
+        let prefix = buffer.len();
+        buffer.write_slice(&[1; 300][..*len]);
+        assert_eq!(buffer.read_lp_varint(&mut out).map(|payload| payload.len()), Some(*len));
+        assert_eq!(buffer.len(), 0);
+        assert_eq!(varint_len(*len as u64), prefix);
+    }
+}
+
+#[test]
+fn test_hex_len() {
+    use core::fmt::Write;
+    use baffa::codec::hex_len;
+
+    for data in [&[][..], &[0][..], &[0xab, 0x01, 0xff][..], &[7; 20][..]].iter() {
+        let mut buffer = StaticBuffer::<[u8; 64]>::new().into_circular();
+        for byte in data.iter() {
+            write!(buffer, "{:02x}", byte).expect("to write");
+        }
+        assert_eq!(hex_len(data.len()), buffer.len());
+    }
+}
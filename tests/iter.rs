@@ -0,0 +1,32 @@
+use baffa::CapacityError;
+use baffa::iter::TryCollect;
+
+#[test]
+fn test_try_collect_buffer() {
+    let buffer = (0u8..4).try_collect_buffer::<u32>().expect("to fit");
+    assert_eq!(buffer.as_slice(), [0, 1, 2, 3]);
+
+    let buffer = (0u8..2).try_collect_buffer::<u32>().expect("to fit");
+    assert_eq!(buffer.as_slice(), [0, 1]);
+
+    assert_eq!((0u8..5).try_collect_buffer::<u32>().unwrap_err(), CapacityError);
+}
+
+#[test]
+fn test_values() {
+    use baffa::{StaticBuffer, ReadBufExt, WriteBuf};
+
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    buffer.write_slice(&1u16.to_ne_bytes());
+    buffer.write_slice(&2u16.to_ne_bytes());
+    buffer.write_slice(&3u16.to_ne_bytes());
+    buffer.write_slice(&[4]);
+
+    let values = unsafe {
+        buffer.values::<u16>()
+    };
+    assert_eq!(values.size_hint(), (3, Some(3)));
+    assert_eq!(values.collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(buffer.len(), 7);
+    assert_eq!(unsafe { buffer.values::<()>() }.next(), None);
+}
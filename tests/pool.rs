@@ -0,0 +1,24 @@
+use baffa::WriteBuf;
+use baffa::pool::BufferPool;
+
+#[test]
+fn test_pool() {
+    let pool = BufferPool::<[u8; 8], 2>::new();
+    assert_eq!(pool.available(), 2);
+
+    let mut first = pool.acquire().expect("to acquire");
+    let second = pool.acquire().expect("to acquire");
+    assert_eq!(pool.available(), 0);
+    assert!(pool.acquire().is_none());
+
+    assert_eq!(first.write_slice(b"abc"), 3);
+    assert_eq!(first.as_slice(), b"abc");
+    assert_eq!(second.len(), 0);
+
+    //buffer is cleared on release
+    drop(first);
+    assert_eq!(pool.available(), 1);
+    let first = pool.acquire().expect("to acquire after release");
+    assert_eq!(first.len(), 0);
+    assert!(pool.acquire().is_none());
+}
@@ -0,0 +1,68 @@
+use baffa::Buf;
+use baffa::rolling::{self, RollingHash, WindowSum};
+
+fn window<S>(hash: &RollingHash<S>) -> ([u8; 8], usize) {
+    let mut bytes = [0u8; 8];
+    let ring = hash.as_ring();
+    for (idx, byte) in ring.iter().enumerate() {
+        bytes[idx] = *byte;
+    }
+    (bytes, ring.len())
+}
+
+#[test]
+fn test_rolling_hash() {
+    let mut hash = RollingHash::<u64>::new();
+    assert_eq!(hash.hash(), rolling::hash(&[]));
+
+    assert_eq!(hash.write_slice(b"hello"), 5);
+    assert_eq!(hash.hash(), rolling::hash(b"hello"));
+
+    //slide window over oldest bytes
+    assert_eq!(hash.write_slice(b" world"), 6);
+    let (bytes, len) = window(&hash);
+    assert_eq!(&bytes[..len], b"lo world");
+    assert_eq!(hash.hash(), rolling::hash(b"lo world"));
+
+    assert_eq!(hash.consume(3), 3);
+    assert_eq!(hash.hash(), rolling::hash(b"world"));
+
+    assert_eq!(hash.write_slice(b"0123456789"), 8);
+    let (bytes, len) = window(&hash);
+    assert_eq!(&bytes[..len], b"23456789");
+    assert_eq!(hash.hash(), rolling::hash(b"23456789"));
+
+    assert_eq!(hash.consume(10), 8);
+    assert_eq!(hash.hash(), rolling::hash(&[]));
+}
+
+#[test]
+fn test_window_sum() {
+    fn manual_sum<S>(sum: &WindowSum<S>) -> u64 {
+        sum.as_ring().iter().fold(0, |acc, byte| acc + *byte as u64)
+    }
+
+    let mut sum = WindowSum::<u32>::new();
+    assert_eq!(sum.sum(), 0);
+    assert_eq!(sum.mean(), 0.0);
+
+    assert_eq!(sum.write_slice(&[10, 20, 30]), 3);
+    assert_eq!(sum.sum(), 60);
+    assert_eq!(sum.mean(), 20.0);
+
+    //overwrite on full
+    assert_eq!(sum.write_slice(&[40, 50]), 2);
+    assert_eq!(sum.sum(), manual_sum(&sum));
+    assert_eq!(sum.sum(), 140);
+
+    assert_eq!(sum.consume(1), 1);
+    assert_eq!(sum.sum(), manual_sum(&sum));
+    assert_eq!(sum.sum(), 120);
+
+    assert_eq!(sum.write_slice(&[255; 6]), 4);
+    assert_eq!(sum.sum(), manual_sum(&sum));
+    assert_eq!(sum.mean(), 255.0);
+
+    assert_eq!(sum.consume(10), 4);
+    assert_eq!(sum.sum(), 0);
+}
@@ -0,0 +1,38 @@
+use baffa::spsc::Queue;
+
+#[test]
+fn test_queue() {
+    let mut queue = Queue::<u32, [u8; 16]>::new();
+    assert!(queue.is_empty());
+    assert_eq!(queue.try_pop(), None);
+
+    for idx in 0..4 {
+        assert!(queue.try_push(idx));
+    }
+    //never over-writes
+    assert!(!queue.try_push(4));
+    assert_eq!(queue.len(), 4);
+
+    assert_eq!(queue.try_pop(), Some(0));
+    assert!(queue.try_push(4));
+    for idx in 1..5 {
+        assert_eq!(queue.try_pop(), Some(idx));
+    }
+    assert_eq!(queue.try_pop(), None);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_queue_wrapping() {
+    let mut queue = Queue::<[u8; 3], u64>::new();
+    assert!(queue.try_push([1, 2, 3]));
+    assert!(queue.try_push([4, 5, 6]));
+    assert!(!queue.try_push([7, 8, 9]));
+
+    assert_eq!(queue.try_pop(), Some([1, 2, 3]));
+    //value wraps around the end of storage
+    assert!(queue.try_push([7, 8, 9]));
+    assert_eq!(queue.try_pop(), Some([4, 5, 6]));
+    assert_eq!(queue.try_pop(), Some([7, 8, 9]));
+    assert_eq!(queue.try_pop(), None);
+}
@@ -1,4 +1,6 @@
-use baffa::{WriteBuf, WriteBufExt, StaticBuffer, ReadBuf, ReadBufExt, ContBuf};
+#![allow(clippy::legacy_numeric_constants)]
+
+use baffa::{Buf, WriteBuf, WriteBufExt, StaticBuffer, ReadBuf, ReadBufExt, PeekBuf, RewindBuf, ContBuf};
 use core::{mem, slice};
 
 #[test]
@@ -123,3 +125,1954 @@ fn test_ring_buffer() {
         res.assume_init()
     }, 0);
 }
+
+#[test]
+fn test_ring_stats() {
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    assert_eq!(buffer.stats(), (0, 8, 8));
+
+    assert_eq!(buffer.write_value(&u32::max_value()), 4);
+    assert_eq!(buffer.stats(), (4, 4, 8));
+    //WriteBuf::remaining for ring always reports full capacity
+    assert_eq!(buffer.remaining(), 8);
+}
+
+#[test]
+fn test_ring_fmt_write() {
+    use core::fmt::Write;
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    write!(buffer, "{}-ab", 12345).expect("ring never fails to write");
+    assert_eq!(buffer.len(), 4);
+    let mut res = [0u8; 4];
+    assert_eq!(buffer.read_slice(&mut res), 4);
+    assert_eq!(&res, b"5-ab");
+}
+
+#[test]
+fn test_take_array() {
+    let mut buffer = StaticBuffer::<u64>::new();
+    assert_eq!(buffer.write_slice(&[1, 2, 3, 4, 5, 6]), 6);
+
+    assert_eq!(buffer.take_array::<4>(), Some([1, 2, 3, 4]));
+    assert_eq!(buffer.len(), 2);
+
+    assert_eq!(buffer.take_array::<4>(), None);
+    assert_eq!(buffer.as_slice(), [5, 6]);
+}
+
+#[test]
+fn test_put_array() {
+    let mut buffer = StaticBuffer::<u32>::new();
+    assert!(buffer.put_array([1, 2, 3]));
+    assert_eq!(buffer.as_slice(), [1, 2, 3]);
+
+    assert!(!buffer.put_array([4, 5]));
+    assert_eq!(buffer.as_slice(), [1, 2, 3]);
+
+    assert!(buffer.put_array([4]));
+    assert_eq!(buffer.as_slice(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn test_endian_view() {
+    use baffa::stack::{Le, Be};
+
+    let mut le = StaticBuffer::<u64>::new();
+    let mut be = StaticBuffer::<u64>::new();
+
+    assert_eq!(Le(&mut le).write_value(&0x1234u16), 2);
+    assert_eq!(Be(&mut be).write_value(&0x1234u16), 2);
+    assert_eq!(le.as_slice(), [0x34, 0x12]);
+    assert_eq!(be.as_slice(), [0x12, 0x34]);
+
+    assert_eq!(Le(&mut le).write_value(&-2i32), 4);
+    assert_eq!(Be(&mut be).write_value(&-2i32), 4);
+    assert_eq!(le.as_slice(), [0x34, 0x12, 0xfe, 0xff, 0xff, 0xff]);
+    assert_eq!(be.as_slice(), [0x12, 0x34, 0xff, 0xff, 0xff, 0xfe]);
+
+    assert_eq!(Le(&mut le).write_value(&1u32), 0);
+
+    let mut res = mem::MaybeUninit::<u16>::new(0);
+    assert_eq!(Le(&mut le).read_value(&mut res), 2);
+    assert_eq!(unsafe { res.assume_init() }, 0x1234);
+    assert_eq!(Be(&mut be).read_value(&mut res), 2);
+    assert_eq!(unsafe { res.assume_init() }, 0x1234);
+
+    let mut res = mem::MaybeUninit::<i32>::new(0);
+    assert_eq!(Le(&mut le).read_value(&mut res), 4);
+    assert_eq!(unsafe { res.assume_init() }, -2);
+    assert_eq!(Be(&mut be).read_value(&mut res), 4);
+    assert_eq!(unsafe { res.assume_init() }, -2);
+    assert_eq!(Be(&mut be).read_value(&mut res), 0);
+}
+
+#[test]
+fn test_stack_buffer_overlapping_write() {
+    let mut buffer = StaticBuffer::<u64>::new();
+    assert_eq!(buffer.write_slice(&[1, 2, 3, 4]), 4);
+    buffer.truncate(2);
+
+    unsafe {
+        let ptr = buffer.as_ptr();
+        buffer.write(ptr, 4);
+    }
+    assert_eq!(buffer.as_slice(), [1, 2, 1, 2, 3, 4]);
+
+    let mut storage = [mem::MaybeUninit::<u8>::uninit(); 6];
+    let mut buffer = baffa::stack::BufferRef::new(&mut storage);
+    assert_eq!(buffer.write_slice(&[1, 2, 3, 4]), 4);
+    buffer.truncate(2);
+
+    unsafe {
+        let ptr = buffer.as_ptr();
+        buffer.write(ptr, 4);
+    }
+    assert_eq!(buffer.as_slice(), [1, 2, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_ring_overflow_hook() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static DROPPED: AtomicUsize = AtomicUsize::new(0);
+    fn on_overflow(dropped: usize) {
+        DROPPED.fetch_add(dropped, Ordering::SeqCst);
+    }
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.set_overflow_hook(on_overflow);
+
+    assert_eq!(buffer.write_slice(&[1, 2, 3]), 3);
+    assert_eq!(DROPPED.load(Ordering::SeqCst), 0);
+
+    assert_eq!(buffer.write_slice(&[4, 5, 6]), 3);
+    assert_eq!(DROPPED.load(Ordering::SeqCst), 2);
+    assert_eq!(buffer.len(), 4);
+
+    assert_eq!(buffer.write_value(&7u8), 1);
+    assert_eq!(DROPPED.load(Ordering::SeqCst), 3);
+
+    //only stored bytes are reported, not skipped part of oversized slice
+    assert_eq!(buffer.write_slice(&[8; 10]), 4);
+    assert_eq!(DROPPED.load(Ordering::SeqCst), 7);
+    assert_eq!(buffer.write_slice_rev(&[9; 10]), 4);
+    assert_eq!(DROPPED.load(Ordering::SeqCst), 11);
+    assert_eq!(buffer.len(), 4);
+}
+
+#[test]
+fn test_ring_write_slice_rev_over_capacity() {
+    let data = *b"0123456789abcdefghij";
+
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    buffer.write_slice(b"xyz");
+    assert_eq!(buffer.write_slice_rev(&data), 8);
+    assert_eq!(buffer.len(), 8);
+
+    //same as writing reversed slice byte by byte
+    let mut expected = StaticBuffer::<u64>::new().into_circular();
+    for byte in data.iter().rev() {
+        expected.write_slice(&[*byte]);
+    }
+
+    let mut res = [0u8; 8];
+    assert_eq!(buffer.read_slice(&mut res), 8);
+    assert_eq!(&res, b"76543210");
+    assert_eq!(expected.read_slice(&mut res), 8);
+    assert_eq!(&res, b"76543210");
+}
+
+#[test]
+fn test_write_int() {
+    let mut buffer = StaticBuffer::<[u8; 32]>::new();
+    assert_eq!(buffer.write_uint(0), 1);
+    assert_eq!(buffer.as_slice(), b"0");
+
+    let mut buffer = StaticBuffer::<[u8; 32]>::new();
+    assert_eq!(buffer.write_uint(u64::max_value()), 20);
+    assert_eq!(buffer.as_slice(), b"18446744073709551615");
+
+    let mut buffer = StaticBuffer::<[u8; 32]>::new();
+    assert_eq!(buffer.write_int(0), 1);
+    assert_eq!(buffer.write_int(-42), 3);
+    assert_eq!(buffer.write_int(i64::min_value()), 20);
+    assert_eq!(buffer.as_slice(), b"0-42-9223372036854775808");
+
+    let mut buffer = StaticBuffer::<u32>::new();
+    assert_eq!(buffer.write_int(-123), 4);
+    assert_eq!(buffer.as_slice(), b"-123");
+    buffer.truncate(1);
+    assert_eq!(buffer.write_int(-123), 0);
+    assert_eq!(buffer.write_uint(1234), 0);
+    assert_eq!(buffer.as_slice(), b"-");
+}
+
+#[test]
+fn test_read_uint() {
+    let mut buffer = StaticBuffer::<u64>::new();
+    assert_eq!(buffer.read_uint(), None);
+
+    assert_eq!(buffer.write_slice(b"123abc"), 6);
+    assert_eq!(buffer.read_uint(), Some(123));
+    assert_eq!(buffer.as_slice(), b"abc");
+    assert_eq!(buffer.read_uint(), None);
+    assert_eq!(buffer.as_slice(), b"abc");
+
+    let mut buffer = StaticBuffer::<[u8; 32]>::new();
+    assert_eq!(buffer.write_slice(b"18446744073709551615"), 20);
+    assert_eq!(buffer.read_uint(), Some(u64::max_value()));
+    assert_eq!(buffer.len(), 0);
+
+    assert_eq!(buffer.write_slice(b"18446744073709551616"), 20);
+    assert_eq!(buffer.read_uint(), None);
+    assert_eq!(buffer.len(), 20);
+}
+
+#[test]
+fn test_buffer_ref() {
+    use baffa::stack::BufferRef;
+
+    let mut storage = [mem::MaybeUninit::<u8>::uninit(); 8];
+    let mut buffer = BufferRef::new(&mut storage);
+    assert_eq!(buffer.capacity(), 8);
+    assert_eq!(buffer.as_write_slice().len(), 8);
+
+    assert_eq!(buffer.write_value(&u32::max_value()), 4);
+    assert_eq!(buffer.write_slice(&[1, 2, 3, 4, 5]), 4);
+    assert_eq!(buffer.remaining(), 0);
+    assert_eq!(buffer.as_read_slice(), [255, 255, 255, 255, 1, 2, 3, 4]);
+
+    let mut res = mem::MaybeUninit::<u32>::new(0);
+    assert_eq!(buffer.read_value(&mut res), 4);
+    assert_eq!(unsafe { res.assume_init() }, u32::max_value());
+    assert_eq!(buffer.as_slice(), [1, 2, 3, 4]);
+    assert_eq!(buffer[3], 4);
+
+    let (_, len) = buffer.into_parts();
+    assert_eq!(len, 4);
+    assert_eq!(unsafe { storage[0].assume_init() }, 1);
+}
+
+#[test]
+fn test_align_to() {
+    let mut buffer = StaticBuffer::<u64>::new();
+    assert_eq!(buffer.align_to(4, 0xff), 0);
+
+    assert_eq!(buffer.write_value(&1u8), 1);
+    assert_eq!(buffer.align_to(4, 0xff), 3);
+    assert_eq!(buffer.as_slice(), [1, 0xff, 0xff, 0xff]);
+    assert_eq!(buffer.align_to(4, 0xff), 0);
+
+    assert_eq!(buffer.write_value(&2u8), 1);
+    assert_eq!(buffer.align_to(8, 0), 3);
+    assert_eq!(buffer.as_slice(), [1, 0xff, 0xff, 0xff, 2, 0, 0, 0]);
+
+    buffer.truncate(5);
+    assert_eq!(buffer.align_to(16, 0), 0);
+    assert_eq!(buffer.len(), 5);
+}
+
+#[test]
+fn test_next_aligned_offset() {
+    let mut buffer = StaticBuffer::<[u8; 32]>::new();
+    assert_eq!(buffer.next_aligned_offset(1), 0);
+    assert_eq!(buffer.next_aligned_offset(8), 0);
+
+    buffer.write_slice(&[0; 3]);
+    assert_eq!(buffer.next_aligned_offset(1), 3);
+    assert_eq!(buffer.next_aligned_offset(4), 4);
+    assert_eq!(buffer.next_aligned_offset(16), 16);
+
+    buffer.write_slice(&[0; 5]);
+    assert_eq!(buffer.next_aligned_offset(8), 8);
+    assert_eq!(buffer.next_aligned_offset(16), 16);
+
+    buffer.write_slice(&[0; 1]);
+    assert_eq!(buffer.next_aligned_offset(8), 16);
+    assert_eq!(buffer.align_to(8, 0), 7);
+    assert_eq!(buffer.len(), 16);
+}
+
+#[test]
+fn test_align_skip() {
+    let mut buffer = StaticBuffer::<[u8; 16]>::new().into_circular();
+    assert_eq!(buffer.align_skip(8), 0);
+
+    assert_eq!(buffer.write_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]), 10);
+    let mut res = [0u8; 3];
+    assert_eq!(buffer.read_slice(&mut res), 3);
+    assert_eq!(buffer.read_offset(), 3);
+
+    assert_eq!(buffer.align_skip(8), 5);
+    assert_eq!(buffer.read_offset(), 8);
+    assert_eq!(buffer[0], 9);
+    assert_eq!(buffer.align_skip(8), 0);
+    assert_eq!(buffer.align_skip(16), 0);
+
+    let mut buffer = StaticBuffer::<u64>::new();
+    assert_eq!(buffer.write_slice(&[1, 2, 3]), 3);
+    assert_eq!(buffer.read_slice(&mut res[..1]), 1);
+    assert_eq!(buffer.align_skip(8), 0);
+    assert_eq!(buffer.as_slice(), [2, 3]);
+}
+
+#[test]
+fn test_ring_snapshot() {
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    assert_eq!(buffer.write_slice(&[1, 2, 3]), 3);
+
+    let snapshot = buffer.snapshot();
+    let mut res = [0u8; 2];
+    assert_eq!(buffer.read_slice(&mut res), 2);
+    assert_eq!(res, [1, 2]);
+    assert_eq!(buffer.len(), 1);
+
+    assert!(buffer.restore(snapshot));
+    assert_eq!(buffer.len(), 3);
+    let mut res = [0u8; 3];
+    assert_eq!(buffer.read_slice(&mut res), 3);
+    assert_eq!(res, [1, 2, 3]);
+
+    //writing over snapshotted bytes invalidates it
+    assert!(buffer.restore(snapshot));
+    assert_eq!(buffer.write_slice(&[4, 5]), 2);
+    assert!(!buffer.restore(snapshot));
+    assert_eq!(buffer.len(), 4);
+    assert_eq!(buffer[0], 2);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ring_iovecs() {
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    assert_eq!(buffer.write_slice(&[1, 2, 3, 4, 5, 6]), 6);
+    let mut res = [0u8; 4];
+    assert_eq!(buffer.read_slice(&mut res), 4);
+    assert_eq!(buffer.write_slice(&[7, 8, 9]), 3);
+
+    //readable: 5, 6, 7, 8 | 9
+    assert_eq!(buffer.as_slices(), (&[5u8, 6, 7, 8][..], &[9u8][..]));
+    let [head, tail] = buffer.as_read_iovecs();
+    assert_eq!(&*head, [5, 6, 7, 8]);
+    assert_eq!(&*tail, [9]);
+
+    //free: positions 1..4
+    let [mut head, tail] = buffer.as_write_iovecs();
+    assert_eq!(head.len(), 3);
+    assert_eq!(tail.len(), 0);
+    head.copy_from_slice(&[10, 11, 12]);
+    unsafe {
+        buffer.advance(3);
+    }
+    assert!(buffer.is_full());
+    assert_eq!(buffer.as_slices(), (&[5u8, 6, 7, 8][..], &[9u8, 10, 11, 12][..]));
+
+    let mut res = [0u8; 6];
+    assert_eq!(buffer.read_slice(&mut res), 6);
+    //free: positions 4..8 | 0..2
+    let [head, tail] = buffer.as_write_iovecs();
+    assert_eq!(&*head, [0; 4]);
+    assert_eq!(&*tail, [0; 2]);
+}
+
+#[test]
+fn test_ct_eq() {
+    let mut buffer = StaticBuffer::<u32>::new();
+    assert!(buffer.ct_eq(&[]));
+    assert_eq!(buffer.write_slice(&[1, 2, 3, 4]), 4);
+
+    assert!(buffer.ct_eq(&[1, 2, 3, 4]));
+    assert!(!buffer.ct_eq(&[1, 2, 3, 5]));
+    assert!(!buffer.ct_eq(&[0, 2, 3, 4]));
+    assert!(!buffer.ct_eq(&[1, 2, 3]));
+    assert!(!buffer.ct_eq(&[1, 2, 3, 4, 5]));
+    assert!(!buffer.ct_eq(&[]));
+}
+
+#[test]
+fn test_stack_buffer_replace() {
+    let mut buffer = StaticBuffer::<[u8; 16]>::new();
+    assert_eq!(buffer.write_slice(b"a-b-c"), 5);
+
+    assert_eq!(buffer.replace(b"-", b"+"), 2);
+    assert_eq!(buffer.as_slice(), b"a+b+c");
+    assert_eq!(buffer.replace(b"", b"+"), 0);
+    assert_eq!(buffer.replace(b"x", b"+"), 0);
+
+    assert_eq!(buffer.replace(b"+", b"::"), 2);
+    assert_eq!(buffer.as_slice(), b"a::b::c");
+
+    assert_eq!(buffer.replace(b"::", b""), 2);
+    assert_eq!(buffer.as_slice(), b"abc");
+
+    let mut buffer = StaticBuffer::<u64>::new();
+    assert_eq!(buffer.write_slice(b"aaaa"), 4);
+    assert_eq!(buffer.replace(b"aa", b"a"), 2);
+    assert_eq!(buffer.as_slice(), b"aa");
+
+    //replaces only as many as fit
+    assert_eq!(buffer.write_slice(b"a"), 1);
+    assert_eq!(buffer.replace(b"a", b"xyz"), 2);
+    assert_eq!(buffer.as_slice(), b"xyzxyza");
+}
+
+#[test]
+fn test_iter_writable_mut() {
+    let mut buffer = StaticBuffer::<u64>::new();
+    assert_eq!(buffer.write_slice(&[1, 2]), 2);
+
+    let mut written = 0;
+    for (idx, byte) in buffer.iter_writable_mut().enumerate() {
+        *byte = mem::MaybeUninit::new(idx as u8 + 3);
+        written += 1;
+    }
+    assert_eq!(written, 6);
+    assert_eq!(buffer.len(), 2);
+
+    unsafe {
+        buffer.advance(written);
+    }
+    assert_eq!(buffer.as_slice(), [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(buffer.iter_writable_mut().count(), 0);
+}
+
+#[test]
+fn test_ring_writable_contiguous() {
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    assert_eq!(buffer.writable_contiguous(), 8);
+
+    assert_eq!(buffer.write_slice(&[1, 2, 3, 4, 5, 6]), 6);
+    assert_eq!(buffer.writable_contiguous(), 2);
+
+    let mut res = [0u8; 4];
+    assert_eq!(buffer.read_slice(&mut res), 4);
+    //6 bytes free, but only 2 before wrap
+    assert_eq!(buffer.writable_contiguous(), 2);
+
+    assert_eq!(buffer.write_slice(&[7, 8, 9]), 3);
+    assert_eq!(buffer.writable_contiguous(), 3);
+
+    assert_eq!(buffer.write_slice(&[10, 11, 12]), 3);
+    assert!(buffer.is_full());
+    assert_eq!(buffer.writable_contiguous(), 0);
+}
+
+#[test]
+fn test_write_slice_rev() {
+    let mut buffer = StaticBuffer::<u64>::new();
+    assert_eq!(buffer.write_slice_rev(&[1, 2, 3, 4]), 4);
+    assert_eq!(buffer.as_slice(), [4, 3, 2, 1]);
+    assert_eq!(buffer.write_slice_rev(&[1, 2, 3, 4, 5, 6]), 4);
+    assert_eq!(buffer.as_slice(), [4, 3, 2, 1, 6, 5, 4, 3]);
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    assert_eq!(buffer.write_slice(&[0, 0, 0]), 3);
+    assert_eq!(buffer.write_slice_rev(&[1, 2, 3, 4]), 4);
+    let mut res = [0u8; 4];
+    assert_eq!(buffer.read_slice(&mut res), 4);
+    assert_eq!(res, [4, 3, 2, 1]);
+}
+
+#[test]
+fn test_can_read_write() {
+    let mut buffer = StaticBuffer::<u32>::new();
+    assert!(buffer.can_write::<u32>());
+    assert!(!buffer.can_write::<u64>());
+    assert!(!buffer.can_write::<()>());
+    assert!(!buffer.can_read::<u8>());
+
+    assert_eq!(buffer.write_value(&1u16), 2);
+    assert!(buffer.can_write::<u16>());
+    assert!(!buffer.can_write::<u32>());
+    assert!(buffer.can_read::<u16>());
+    assert!(!buffer.can_read::<u32>());
+    assert!(!buffer.can_read::<()>());
+}
+
+#[test]
+fn test_buffer_refill() {
+    let mut stream = StaticBuffer::<[u8; 32]>::new();
+    for frame in [&b"abc"[..], b"defgh", b"", b"ij"].iter() {
+        stream.write_slice(&[frame.len() as u8]);
+        stream.write_slice(frame);
+    }
+
+    let mut buffer = StaticBuffer::<[u8; 6]>::new();
+    let mut frames = Vec::new();
+    loop {
+        let added = buffer.refill(&mut stream);
+
+        while buffer.len() > 0 && buffer.len() > buffer[0] as usize {
+            let len = buffer[0] as usize;
+            frames.push(buffer.as_slice()[1..=len].to_vec());
+            unsafe {
+                buffer.consume(len + 1);
+            }
+        }
+
+        if added == 0 {
+            break;
+        }
+    }
+
+    assert_eq!(frames, [&b"abc"[..], b"defgh", b"", b"ij"]);
+    assert_eq!(buffer.len(), 0);
+    assert_eq!(stream.len(), 0);
+}
+
+#[test]
+fn test_ring_positions() {
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    assert_eq!((buffer.read_pos(), buffer.write_pos()), (0, 0));
+
+    buffer.write_slice(b"abc");
+    let mut res = [0u8; 2];
+    assert_eq!(buffer.read_slice(&mut res), 2);
+    buffer.write_slice(b"def");
+    assert_eq!((buffer.read_pos(), buffer.write_pos()), (2, 6));
+
+    let (read_pos, write_pos) = (buffer.read_pos(), buffer.write_pos());
+    let (inner, read) = buffer.into_parts();
+    assert_eq!(read_pos, read);
+    assert_eq!(write_pos, inner.len());
+}
+
+#[test]
+fn test_try_from_parts() {
+    use baffa::stack::{PartsError, Ring};
+
+    let buffer = StaticBuffer::try_from_parts(*b"abcd", 3).expect("valid cursor");
+    assert_eq!(buffer.as_slice(), b"abc");
+    assert!(StaticBuffer::try_from_parts(*b"abcd", 4).is_ok());
+    assert_eq!(StaticBuffer::try_from_parts(*b"abcd", 5).unwrap_err(), PartsError::CursorOverflow);
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(b"abcd");
+    buffer.write_slice(b"ef");
+    let (inner, _) = buffer.into_parts();
+    assert_eq!(inner.len(), 6);
+
+    let ring = Ring::try_from_parts(inner, 3).expect("valid read position");
+    assert_eq!(ring.as_slices(), (&b"d"[..], &b"ef"[..]));
+    let (inner, _) = ring.into_parts();
+    assert!(Ring::try_from_parts(inner, 6).unwrap().is_empty());
+
+    let (inner, _) = StaticBuffer::<u32>::new().into_circular().into_parts();
+    assert_eq!(Ring::try_from_parts(inner, 1).err(), Some(PartsError::ReadOverflow));
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(b"abcd");
+    buffer.write_slice(b"ef");
+    let (inner, _) = buffer.into_parts();
+    assert_eq!(Ring::try_from_parts(inner, 1).err(), Some(PartsError::LenOverflow));
+}
+
+#[test]
+fn test_write_interleaved() {
+    let mut buffer = StaticBuffer::<[u8; 10]>::new();
+    assert_eq!(buffer.write_interleaved(b"aceg", b"bdfh"), 8);
+    assert_eq!(buffer.as_slice(), b"abcdefgh");
+    assert_eq!(buffer.write_interleaved(b"12", b"34"), 2);
+    assert_eq!(buffer.as_slice(), b"abcdefgh13");
+
+    let mut buffer = StaticBuffer::<[u8; 10]>::new();
+    assert_eq!(buffer.write_interleaved(b"ace", b"bd"), 5);
+    assert_eq!(buffer.write_interleaved(b"1", b"234"), 2);
+    assert_eq!(buffer.write_interleaved(b"", b"5"), 0);
+    assert_eq!(buffer.as_slice(), b"abcde12");
+}
+
+#[test]
+fn test_read_deinterleaved() {
+    let mut buffer = StaticBuffer::<[u8; 10]>::new();
+    assert_eq!(buffer.write_interleaved(b"aceg", b"bdfh"), 8);
+
+    let mut a = [0u8; 4];
+    let mut b = [0u8; 4];
+    assert_eq!(buffer.read_deinterleaved(&mut a, &mut b), 8);
+    assert_eq!((&a, &b), (b"aceg", b"bdfh"));
+    assert_eq!(buffer.len(), 0);
+
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    assert_eq!(buffer.write_slice(b"xx"), 2);
+    unsafe {
+        buffer.consume(2);
+    }
+    assert_eq!(buffer.write_interleaved(b"ace", b"bd"), 5);
+
+    let mut a = [0u8; 4];
+    let mut b = [0u8; 4];
+    assert_eq!(buffer.read_deinterleaved(&mut a, &mut b), 5);
+    assert_eq!((&a[..3], &b[..2]), (&b"ace"[..], &b"bd"[..]));
+    assert!(buffer.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_io_write_all_full() {
+    use std::io::{self, Write};
+
+    let mut buffer = StaticBuffer::<u32>::new();
+    assert_eq!(Write::write(&mut buffer, b"abcdef").expect("to write"), 4);
+    assert_eq!(Write::write(&mut buffer, b"ef").expect("to write"), 0);
+
+    let mut buffer = StaticBuffer::<u32>::new();
+    let error = buffer.write_all(b"abcdef").unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+    assert_eq!(buffer.as_slice(), b"abcd");
+}
+
+#[test]
+fn test_peek_at() {
+    let mut buffer = StaticBuffer::<u32>::new();
+    buffer.write_slice(b"abc");
+    assert_eq!(buffer.peek_at(0), Some(b'a'));
+    assert_eq!(buffer.peek_at(2), Some(b'c'));
+    assert_eq!(buffer.peek_at(3), None);
+    assert_eq!(buffer.len(), 3);
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(b"abc");
+    unsafe {
+        buffer.consume(2);
+    }
+    buffer.write_slice(b"def");
+    assert_eq!(buffer.as_slices(), (&b"cd"[..], &b"ef"[..]));
+    assert_eq!(buffer.peek_at(1), Some(b'd'));
+    assert_eq!(buffer.peek_at(2), Some(b'e'));
+    assert_eq!(buffer.peek_at(3), Some(b'f'));
+    assert_eq!(buffer.peek_at(4), None);
+    assert_eq!(buffer.len(), 4);
+}
+
+#[test]
+fn test_fill_from() {
+    let mut src = StaticBuffer::<[u8; 200]>::new();
+    for idx in 0..200 {
+        src.write_slice(&[idx as u8]);
+    }
+
+    let mut buffer = StaticBuffer::<[u8; 150]>::new();
+    assert_eq!(buffer.fill_from(&mut src, 100), 100);
+    assert_eq!(buffer.len(), 100);
+    assert_eq!(src.len(), 100);
+    assert!(buffer.as_slice().iter().enumerate().all(|(idx, byte)| idx as u8 == *byte));
+    assert_eq!(src[0], 100);
+
+    assert_eq!(buffer.fill_from(&mut src, 100), 50);
+    assert_eq!(buffer.fill_from(&mut src, 100), 0);
+    assert_eq!(buffer[149], 149);
+    assert_eq!(src.len(), 50);
+}
+
+#[test]
+fn test_fill_from_ring() {
+    let mut src = StaticBuffer::<[u8; 8]>::new().into_circular();
+    src.write_slice(b"abcdef");
+    unsafe {
+        src.consume(4);
+    }
+    src.write_slice(b"ghij");
+    //wraps around, hence copied through chunks
+    assert_eq!(src.as_slices(), (&b"efgh"[..], &b"ij"[..]));
+
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    assert_eq!(buffer.fill_from(&mut src, 5), 5);
+    assert_eq!(buffer.as_slice(), b"efghi");
+    assert_eq!(src.len(), 1);
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    let mut src = StaticBuffer::<u64>::new();
+    src.write_slice(b"klmno");
+    assert_eq!(buffer.fill_from(&mut src, 10), 4);
+    assert_eq!(buffer.as_slices(), (&b"klmn"[..], &[][..]));
+    assert_eq!(src.as_slice(), b"o");
+}
+
+#[test]
+fn test_ring_slice_to_array() {
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    buffer.write_slice(b"abcdef");
+    unsafe {
+        buffer.consume(5);
+    }
+    buffer.write_slice(b"ghijk");
+    assert_eq!(buffer.as_slices(), (&b"fgh"[..], &b"ijk"[..]));
+
+    assert_eq!(buffer.slice_to_array::<2>(0), Some(*b"fg"));
+    assert_eq!(buffer.slice_to_array::<3>(1), Some(*b"ghi"));
+    assert_eq!(buffer.slice_to_array::<6>(0), Some(*b"fghijk"));
+    assert_eq!(buffer.slice_to_array::<2>(4), Some(*b"jk"));
+    assert_eq!(buffer.slice_to_array::<0>(6), Some([]));
+    assert_eq!(buffer.slice_to_array::<2>(5), None);
+    assert_eq!(buffer.slice_to_array::<1>(usize::MAX), None);
+}
+
+#[test]
+fn test_ring_drop_front() {
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    buffer.write_slice(b"abc");
+    unsafe {
+        buffer.consume(3);
+    }
+    buffer.write_slice(b"01234567");
+    assert!(buffer.is_full());
+
+    assert_eq!(buffer.drop_front(4), 4);
+    assert_eq!(buffer.len(), 4);
+    assert_eq!(buffer.slice_to_array::<4>(0), Some(*b"4567"));
+
+    assert_eq!(buffer.drop_front(10), 4);
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.drop_front(1), 0);
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_ring_conformance() {
+    baffa::testing::ring_conformance(|| StaticBuffer::<[u8; 8]>::new().into_circular(), 8);
+    baffa::testing::ring_conformance(|| StaticBuffer::<u16>::new().into_circular(), 2);
+}
+
+#[test]
+fn test_buffer_into_inner() {
+    let mut buffer = StaticBuffer::<u32>::new();
+    assert_eq!(buffer.write_slice(&0x01020304u32.to_ne_bytes()), 4);
+    let value = unsafe {
+        buffer.into_inner()
+    };
+    assert_eq!(value, 0x01020304);
+}
+
+#[test]
+fn test_buffer_storage_align() {
+    assert_eq!(StaticBuffer::<u64>::new().storage_align(), 8);
+    assert_eq!(StaticBuffer::<[u8; 8]>::new().storage_align(), 1);
+}
+
+#[test]
+fn test_ring_write_over_capacity() {
+    let mut data = [0u8; 35];
+    for (idx, byte) in data.iter_mut().enumerate() {
+        *byte = idx as u8;
+    }
+
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    buffer.write_slice(b"abc");
+    unsafe {
+        buffer.write(data.as_ptr(), data.len());
+    }
+    assert!(buffer.is_full());
+    assert_eq!(buffer.slice_to_array::<8>(0), Some([27, 28, 29, 30, 31, 32, 33, 34]));
+    assert_eq!(buffer.write_pos(), 38);
+}
+
+#[test]
+fn test_ring_write_twice_over_capacity() {
+    let data = *b"0123456789abcdefghij";
+
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    unsafe {
+        buffer.write(data.as_ptr(), data.len());
+    }
+    assert_eq!(buffer.len(), 8);
+    assert_eq!(buffer.as_slices(), (&b"cdef"[..], &b"ghij"[..]));
+
+    let mut res = [0u8; 8];
+    assert_eq!(buffer.read_slice(&mut res), 8);
+    assert_eq!(&res, b"cdefghij");
+
+    //safe path keeps the same bytes
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    buffer.write_slice(b"xyz");
+    assert_eq!(buffer.write_slice(&data), 8);
+    assert_eq!(buffer.len(), 8);
+    assert_eq!(buffer.read_slice(&mut res), 8);
+    assert_eq!(&res, b"cdefghij");
+    assert_eq!(buffer.write_slice(&[]), 0);
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn test_ring_advance_len_invariant() {
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    let mut res = [0u8; 3];
+
+    for idx in 0..64usize {
+        buffer.write_slice(&[idx as u8; 3][..idx % 4]);
+        assert!(buffer.len() <= 4);
+        if idx % 3 == 0 {
+            buffer.read_slice(&mut res[..core::cmp::min(buffer.len(), idx % 4)]);
+        }
+    }
+    buffer.write_slice(b"abcd");
+    assert!(buffer.is_full());
+}
+
+#[test]
+fn test_ring_save_load_state() {
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    buffer.write_slice(b"abcdef");
+    unsafe {
+        buffer.consume(4);
+    }
+    buffer.write_slice(b"ghijk");
+    assert_eq!(buffer.as_slices(), (&b"efgh"[..], &b"ijk"[..]));
+
+    let state = buffer.save_state();
+    assert_eq!(state.read, buffer.read_pos());
+    assert_eq!(state.cursor, buffer.write_pos());
+    let mut storage = [0u8; 8];
+    for (dst, src) in storage.iter_mut().zip(buffer.storage()) {
+        *dst = unsafe {
+            src.assume_init()
+        };
+    }
+
+    let mut restored = StaticBuffer::<u64>::new().into_circular();
+    for (dst, src) in restored.storage_mut().iter_mut().zip(storage.iter()) {
+        *dst = mem::MaybeUninit::new(*src);
+    }
+    unsafe {
+        restored.load_state(state);
+    }
+    assert_eq!(restored.len(), 7);
+    assert_eq!(restored.as_slices(), (&b"efgh"[..], &b"ijk"[..]));
+}
+
+#[test]
+fn test_read_lp_varint() {
+    let mut out = [0u8; 200];
+
+    let mut buffer = StaticBuffer::<[u8; 256]>::new();
+    assert_eq!(buffer.read_lp_varint(&mut out), None);
+    //incomplete prefix
+    buffer.write_slice(&[0x80]);
+    assert_eq!(buffer.read_lp_varint(&mut out), None);
+    assert_eq!(buffer.len(), 1);
+    //incomplete payload
+    buffer.write_slice(&[0x01]);
+    buffer.write_slice(&[7; 127]);
+    assert_eq!(buffer.read_lp_varint(&mut out), None);
+    assert_eq!(buffer.len(), 129);
+    //complete message
+    buffer.write_slice(&[7, 3]);
+    buffer.write_slice(b"abc");
+    assert_eq!(buffer.read_lp_varint(&mut out).map(|payload| payload.len()), Some(128));
+    assert!(out[..128].iter().all(|byte| *byte == 7));
+    assert_eq!(buffer.len(), 4);
+    assert_eq!(buffer.read_lp_varint(&mut out), Some(&b"abc"[..]));
+    assert_eq!(buffer.len(), 0);
+
+    buffer.write_slice(&[0]);
+    assert_eq!(buffer.read_lp_varint(&mut out), Some(&[][..]));
+    //payload doesn't fit output
+    buffer.write_slice(&[3]);
+    buffer.write_slice(b"abc");
+    assert_eq!(buffer.read_lp_varint(&mut out[..2]), None);
+    assert_eq!(buffer.len(), 4);
+}
+
+#[test]
+fn test_ring_cycle_bytes() {
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    assert_eq!(buffer.cycle_bytes().next(), None);
+
+    buffer.write_slice(b"xyab");
+    unsafe {
+        buffer.consume(2);
+    }
+    buffer.write_slice(b"c");
+    let bytes = buffer.cycle_bytes().take(2 * buffer.len() + 1).collect::<Vec<_>>();
+    assert_eq!(bytes, b"abcabca");
+    assert_eq!(buffer.len(), 3);
+}
+
+#[test]
+fn test_ring_consume_zeroed() {
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(b"abc");
+    unsafe {
+        buffer.consume(2);
+    }
+    buffer.write_slice(b"def");
+    assert_eq!(buffer.as_slices(), (&b"cd"[..], &b"ef"[..]));
+
+    buffer.consume_zeroed(3);
+    assert_eq!(buffer.len(), 1);
+    assert_eq!(buffer[0], b'f');
+    let storage = buffer.storage();
+    let storage = unsafe {
+        slice::from_raw_parts(storage.as_ptr() as *const u8, storage.len())
+    };
+    assert_eq!(storage, [0, b'f', 0, 0]);
+
+    buffer.consume_zeroed(10);
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn test_rle() {
+    let mut data = [0u8; 600];
+    data[0] = 1;
+    data[1] = 2;
+    for byte in data[2..302].iter_mut() {
+        *byte = 3;
+    }
+    data[599] = 4;
+
+    let mut buffer = StaticBuffer::<[u8; 16]>::new();
+    assert_eq!(buffer.write_rle(&data), data.len());
+    //1, 2, 255 + 45 of 3s, 255 + 42 of 0s, 4
+    assert_eq!(buffer.as_slice(), [1, 1, 1, 2, 255, 3, 45, 3, 255, 0, 42, 0, 1, 4]);
+
+    let mut out = [1u8; 600];
+    assert_eq!(buffer.read_rle(&mut out[..300]), 257);
+    assert_eq!(buffer.len(), 8);
+    assert_eq!(buffer.read_rle(&mut out[257..]), 343);
+    assert_eq!(buffer.len(), 0);
+    assert_eq!(&out[..], &data[..]);
+
+    let mut buffer = StaticBuffer::<[u8; 5]>::new();
+    assert_eq!(buffer.write_rle(&[1, 1, 2, 3]), 3);
+    assert_eq!(buffer.as_slice(), [2, 1, 1, 2]);
+}
+
+#[test]
+fn test_buffer_insert() {
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    buffer.write_slice(b"abcd");
+    assert_eq!(buffer.insert(2, b"12"), 2);
+    assert_eq!(buffer.as_slice(), b"ab12cd");
+    assert_eq!(buffer.insert(6, b"e"), 1);
+    assert_eq!(buffer.insert(0, b"xyz"), 0);
+    assert_eq!(buffer.as_slice(), b"ab12cde");
+    assert_eq!(buffer.insert(0, b"0"), 1);
+    assert_eq!(buffer.as_slice(), b"0ab12cde");
+    assert_eq!(buffer.insert(8, b""), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_buffer_insert_out_of_bounds() {
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    buffer.write_slice(b"ab");
+    buffer.insert(3, b"c");
+}
+
+#[test]
+fn test_buffer_remove_range() {
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    buffer.write_slice(b"ab12cd");
+    buffer.remove_range(2..4);
+    assert_eq!(buffer.as_slice(), b"abcd");
+    buffer.remove_range(1..1);
+    assert_eq!(buffer.as_slice(), b"abcd");
+    buffer.remove_range(3..4);
+    buffer.remove_range(0..1);
+    assert_eq!(buffer.as_slice(), b"bc");
+    buffer.remove_range(0..2);
+    assert_eq!(buffer.len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_buffer_remove_range_out_of_bounds() {
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    buffer.write_slice(b"ab");
+    buffer.remove_range(1..3);
+}
+
+#[test]
+fn test_take_until() {
+    let mut out = [0u8; 4];
+
+    let mut buffer = StaticBuffer::<[u8; 16]>::new();
+    buffer.write_slice(b"ab,,cdefg,hi");
+    assert_eq!(buffer.take_until(b',', &mut out), Some(&b"ab"[..]));
+    assert_eq!(buffer.take_until(b',', &mut out), Some(&b""[..]));
+    //token doesn't fit
+    assert_eq!(buffer.take_until(b',', &mut out), None);
+    assert_eq!(buffer.as_slice(), b"cdefg,hi");
+    assert_eq!(buffer.take_until(b';', &mut out), None);
+    assert_eq!(buffer.len(), 8);
+
+    //borrowed token is not limited by output
+    let token = buffer.peek_until(b',').expect("to find delimiter");
+    assert_eq!(token, b"cdefg");
+    let token_len = token.len();
+    unsafe {
+        buffer.consume(token_len + 1);
+    }
+    assert_eq!(buffer.as_slice(), b"hi");
+    assert_eq!(buffer.peek_until(b','), None);
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(b"xxa");
+    unsafe {
+        buffer.consume(2);
+    }
+    buffer.write_slice(b"b\n");
+    assert_eq!(buffer.take_until(b'\n', &mut out), Some(&b"ab"[..]));
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.take_until(b'\n', &mut out), None);
+}
+
+#[test]
+fn test_read_while() {
+    let mut out = [0u8; 4];
+
+    let mut buffer = StaticBuffer::<[u8; 16]>::new();
+    buffer.write_slice(b"123abc");
+    assert_eq!(buffer.read_while(&mut out, |byte| byte.is_ascii_digit()), 3);
+    assert_eq!(&out[..3], b"123");
+    assert_eq!(buffer.as_slice(), b"abc");
+    assert_eq!(buffer.read_while(&mut out, |byte| byte.is_ascii_digit()), 0);
+    assert_eq!(buffer.as_slice(), b"abc");
+
+    //stops when out is full
+    buffer.consume_all();
+    buffer.write_slice(b"1234567");
+    assert_eq!(buffer.read_while(&mut out, |byte| byte.is_ascii_digit()), 4);
+    assert_eq!(out, *b"1234");
+    assert_eq!(buffer.read_while(&mut out, |byte| byte.is_ascii_digit()), 3);
+    assert_eq!(&out[..3], b"567");
+    assert_eq!(buffer.len(), 0);
+}
+
+#[test]
+fn test_buffer_split_at() {
+    let mut buffer = StaticBuffer::<u64>::new();
+    buffer.write_slice(b"abcdef");
+
+    let (head, tail) = buffer.split_at(3);
+    assert_eq!(head, b"abc");
+    assert_eq!(tail, b"def");
+    assert_eq!(buffer.split_at(6), (&b"abcdef"[..], &b""[..]));
+
+    let (head, tail) = buffer.split_at_mut(3);
+    head.swap_with_slice(tail);
+    assert_eq!(buffer.as_slice(), b"defabc");
+}
+
+#[test]
+#[should_panic]
+fn test_buffer_split_at_overflow() {
+    let mut buffer = StaticBuffer::<u64>::new();
+    buffer.write_slice(b"abcdef");
+    buffer.split_at(7);
+}
+
+#[test]
+fn test_buffer_consume_all() {
+    let mut buffer = StaticBuffer::<u32>::new();
+    buffer.consume_all();
+    assert_eq!(buffer.len(), 0);
+
+    buffer.write_slice(b"abc");
+    let ptr = buffer.as_slice().as_ptr();
+    buffer.consume_all();
+    assert_eq!(buffer.len(), 0);
+    assert_eq!(buffer.remaining(), 4);
+
+    buffer.write_slice(b"de");
+    assert_eq!(buffer.as_slice(), b"de");
+    assert_eq!(buffer.as_slice().as_ptr(), ptr);
+}
+
+#[test]
+fn test_get_range() {
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    buffer.write_slice(b"abcd");
+    assert_eq!(buffer.get_range(1..3), Some(&b"bc"[..]));
+    assert_eq!(buffer.get_range(0..4), Some(&b"abcd"[..]));
+    assert_eq!(buffer.get_range(3..5), None);
+    #[allow(clippy::reversed_empty_ranges)]
+    let range = 3..2;
+    assert_eq!(buffer.get_range(range), None);
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(b"xxab");
+    unsafe {
+        buffer.consume(2);
+    }
+    buffer.write_slice(b"cd");
+    assert_eq!(buffer.get_range(0..2), Some(&b"ab"[..]));
+    assert_eq!(buffer.get_range(2..4), Some(&b"cd"[..]));
+    assert_eq!(buffer.get_range(2..2), Some(&b""[..]));
+    //wraps around the end of storage
+    assert_eq!(buffer.get_range(1..3), None);
+    assert_eq!(buffer.get_range(3..5), None);
+}
+
+#[test]
+fn test_write_field() {
+    let mut buffer = StaticBuffer::<[u8; 16]>::new();
+    assert_eq!(buffer.write_field(b"ab", 4, b' '), 4);
+    assert_eq!(buffer.write_field(b"cdef", 4, b' '), 4);
+    assert_eq!(buffer.write_field(b"ghijkl", 4, b' '), 4);
+    assert_eq!(buffer.as_slice(), b"ab  cdefghij");
+
+    //partial write
+    assert_eq!(buffer.write_field(b"x", 8, b'0'), 4);
+    assert_eq!(buffer.as_slice(), b"ab  cdefghijx000");
+    assert_eq!(buffer.write_field(b"x", 8, b'0'), 0);
+}
+
+#[test]
+fn test_peek_buf() {
+    fn magic<B: PeekBuf>(buf: &B) -> Option<bool> {
+        buf.peek_slice(2).map(|magic| magic == b"MZ")
+    }
+
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    assert_eq!(magic(&buffer), None);
+    buffer.write_slice(b"MZab");
+    assert_eq!(magic(&buffer), Some(true));
+    assert_eq!(buffer.len(), 4);
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(b"xxxM");
+    unsafe {
+        buffer.consume(3);
+    }
+    buffer.write_slice(b"Z");
+    //lookahead wraps
+    assert_eq!(magic(&buffer), None);
+    unsafe {
+        buffer.consume(1);
+    }
+    buffer.write_slice(b"MZ");
+    assert_eq!(magic(&buffer), Some(false));
+}
+
+#[test]
+fn test_value_count() {
+    let mut buffer = StaticBuffer::<u64>::new();
+    assert_eq!(buffer.value_count::<u32>(), 0);
+    buffer.write_slice(&[1; 7]);
+    assert_eq!(buffer.value_count::<u32>(), 1);
+    buffer.write_slice(&[1]);
+    assert_eq!(buffer.value_count::<u32>(), 2);
+    assert_eq!(buffer.value_count::<u8>(), 8);
+    assert_eq!(buffer.value_count::<u128>(), 0);
+    assert_eq!(buffer.value_count::<()>(), 0);
+}
+
+#[test]
+fn test_broadcast() {
+    let mut buffer = StaticBuffer::<[u8; 100]>::new();
+    for idx in 0..100 {
+        buffer.write_slice(&[idx as u8]);
+    }
+
+    let mut a = StaticBuffer::<[u8; 80]>::new();
+    let mut b = StaticBuffer::<[u8; 90]>::new();
+    b.write_slice(b"b");
+    assert_eq!(buffer.broadcast(&mut a, &mut b), 80);
+    assert_eq!(buffer.len(), 20);
+    assert_eq!(buffer[0], 80);
+    assert_eq!(a.as_slice(), &b.as_slice()[1..]);
+    assert!(a.as_slice().iter().enumerate().all(|(idx, byte)| idx as u8 == *byte));
+
+    assert_eq!(buffer.broadcast(&mut a, &mut b), 0);
+    assert_eq!(buffer.len(), 20);
+}
+
+#[test]
+fn test_hamming_distance() {
+    let mut buffer = StaticBuffer::<u32>::new();
+    assert_eq!(buffer.hamming_distance(&[]), Some(0));
+
+    buffer.write_slice(b"karo");
+    assert_eq!(buffer.hamming_distance(b"karo"), Some(0));
+    //0b01101111 ^ 0b01100001
+    assert_eq!(buffer.hamming_distance(b"kara"), Some(3));
+    assert_eq!(buffer.hamming_distance(&[!b'k', !b'a', !b'r', !b'o']), Some(32));
+    assert_eq!(buffer.hamming_distance(b"kar"), None);
+}
+
+#[test]
+fn test_xor_with() {
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    buffer.write_slice(b"secret");
+    assert_eq!(buffer.xor_with(&[]), 0);
+    assert_eq!(buffer.xor_with(&[0xff, 0x01]), 6);
+    assert_eq!(buffer.as_slice(), [!b's', b'e' ^ 1, !b'c', b'r' ^ 1, !b'e', b't' ^ 1]);
+    assert_eq!(buffer.xor_with(&[0xff, 0x01]), 6);
+    assert_eq!(buffer.as_slice(), b"secret");
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(b"xxab");
+    unsafe {
+        buffer.consume(2);
+    }
+    buffer.write_slice(b"c");
+    assert_eq!(buffer.xor_with(&[1, 2, 3]), 3);
+    assert_eq!(buffer.slice_to_array::<3>(0), Some([b'a' ^ 1, b'b' ^ 2, b'c' ^ 3]));
+    assert_eq!(buffer.xor_with(&[1, 2, 3]), 3);
+    assert_eq!(buffer.slice_to_array::<3>(0), Some(*b"abc"));
+}
+
+#[test]
+fn test_write_int_slice() {
+    let mut buffer = StaticBuffer::<[u8; 10]>::new();
+    assert_eq!(buffer.write_u32_slice_le(&[0x01020304, 0x05060708]), 8);
+    assert_eq!(buffer.as_slice(), [4, 3, 2, 1, 8, 7, 6, 5]);
+    //stops on element boundary
+    assert_eq!(buffer.write_u32_slice_be(&[1]), 0);
+    assert_eq!(buffer.write_u16_slice_be(&[0x0102, 0x0304]), 2);
+    assert_eq!(buffer.as_slice(), [4, 3, 2, 1, 8, 7, 6, 5, 1, 2]);
+
+    let mut buffer = StaticBuffer::<[u8; 10]>::new();
+    assert_eq!(buffer.write_u16_slice_le(&[0x0102, 0x0304]), 4);
+    assert_eq!(buffer.write_u32_slice_be(&[0x01020304, 0x05060708]), 4);
+    assert_eq!(buffer.as_slice(), [2, 1, 4, 3, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_ring_transaction() {
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(b"abc");
+    let mut res = [0u8; 2];
+
+    let mut txn = buffer.begin_transaction();
+    assert_eq!(txn.read_slice(&mut res), 2);
+    assert_eq!(txn.len(), 1);
+    assert!(txn.rollback());
+    assert_eq!(buffer.as_slices(), (&b"abc"[..], &b""[..]));
+
+    {
+        let mut txn = buffer.begin_transaction();
+        assert_eq!(txn.read_slice(&mut res), 2);
+    }
+    assert_eq!(buffer.len(), 3);
+
+    let mut txn = buffer.begin_transaction();
+    assert_eq!(txn.read_slice(&mut res), 2);
+    txn.commit();
+    assert_eq!(buffer.len(), 1);
+
+    //read bytes are over-written
+    let mut txn = buffer.begin_transaction();
+    assert_eq!(txn.read_slice(&mut res[..1]), 1);
+    txn.write_slice(b"defg");
+    assert!(!txn.rollback());
+    assert_eq!(buffer.slice_to_array::<4>(0), Some(*b"defg"));
+}
+
+#[test]
+fn test_buffer_ascii_case() {
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    buffer.write_slice(b"Hello");
+    buffer.make_ascii_uppercase();
+    assert_eq!(buffer.as_slice(), b"HELLO");
+
+    buffer.write_slice(&[b'-', 0xc4]);
+    buffer.make_ascii_lowercase();
+    assert_eq!(buffer.as_slice(), [b'h', b'e', b'l', b'l', b'o', b'-', 0xc4]);
+}
+
+#[test]
+fn test_buffer_trim_ascii() {
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    buffer.write_slice(b"  hi \t");
+    buffer.trim_ascii();
+    assert_eq!(buffer.as_slice(), b"hi");
+    buffer.trim_ascii();
+    assert_eq!(buffer.as_slice(), b"hi");
+
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    buffer.write_slice(b" \r\n ");
+    buffer.trim_ascii();
+    assert_eq!(buffer.len(), 0);
+    buffer.trim_ascii();
+    assert_eq!(buffer.len(), 0);
+}
+
+#[test]
+fn test_deltas() {
+    let samples = [1000, 1001, 1003, 1003, 1100, 100_000, -5, i32::MAX, i32::MIN];
+
+    let mut buffer = StaticBuffer::<[u8; 32]>::new();
+    //4 + 1 + 1 + 1 + 2 + 3 + 3 + 5 + 1
+    assert_eq!(buffer.write_deltas(&samples), 21);
+
+    let mut out = [0i32; 16];
+    assert_eq!(buffer.read_deltas(&mut out), samples.len());
+    assert_eq!(&out[..samples.len()], &samples[..]);
+    assert_eq!(buffer.len(), 0);
+
+    //capacity cut off at sample boundary
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    assert_eq!(buffer.write_deltas(&samples), 7);
+    assert_eq!(buffer.read_deltas(&mut out[..2]), 2);
+    assert_eq!(&out[..2], &samples[..2]);
+    assert_eq!(buffer.len(), 2);
+}
+
+#[test]
+fn test_as_str() {
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    assert_eq!(buffer.as_str(), Some(""));
+    buffer.write_slice("héllo".as_bytes());
+    assert_eq!(buffer.as_str(), Some("héllo"));
+    buffer.write_slice(&[0xff]);
+    assert_eq!(buffer.as_str(), None);
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(b"xxab");
+    unsafe {
+        buffer.consume(2);
+    }
+    assert_eq!(buffer.as_str(), Some("ab"));
+    buffer.write_slice(b"c");
+    //not contiguous
+    assert_eq!(buffer.as_str(), None);
+}
+
+#[test]
+fn test_read_str() {
+    let mut out = [0u8; 8];
+
+    let mut buffer = StaticBuffer::<[u8; 16]>::new();
+    buffer.write_slice("héllo".as_bytes());
+    buffer.write_slice(&[0xff]);
+    assert_eq!(buffer.read_str(&mut out, 3), Some("hé"));
+    assert_eq!(buffer.len(), 4);
+    //invalid UTF-8
+    assert_eq!(buffer.read_str(&mut out, 4), None);
+    assert_eq!(buffer.len(), 4);
+    //not enough bytes
+    assert_eq!(buffer.read_str(&mut out, 5), None);
+    assert_eq!(buffer.read_str(&mut out, 3), Some("llo"));
+    assert_eq!(buffer.read_str(&mut out, 0), Some(""));
+    assert_eq!(buffer.as_slice(), [0xff]);
+}
+
+#[test]
+fn test_ring_mask() {
+    use baffa::stack::Ring;
+
+    assert_eq!(Ring::<u64>::mask(), 7);
+    assert_eq!(Ring::<[u8; 16]>::mask(), 15);
+    for idx in [0, 1, 7, 8, 9, 100, usize::MAX].iter() {
+        assert_eq!(Ring::<u64>::mask_index(*idx), *idx % 8);
+    }
+
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    buffer.write_slice(b"abcdef");
+    unsafe {
+        buffer.consume(5);
+    }
+    buffer.write_slice(b"ghijk");
+    let idx = Ring::<u64>::mask_index(buffer.read_pos());
+    assert_eq!(idx, 5);
+    assert_eq!(unsafe { buffer.storage()[idx].assume_init() }, b'f');
+}
+
+#[test]
+fn test_push_pop() {
+    let mut buffer = StaticBuffer::<u16>::new();
+    assert!(buffer.push(1));
+    assert!(buffer.push(2));
+    assert!(!buffer.push(3));
+    assert_eq!(buffer.pop(), Some(1));
+    assert_eq!(buffer.pop(), Some(2));
+    assert_eq!(buffer.pop(), None);
+
+    let mut buffer = StaticBuffer::<u16>::new().into_circular();
+    assert!(buffer.push(1));
+    assert!(buffer.push(2));
+    //over-writes oldest
+    assert!(buffer.push(3));
+    assert_eq!(buffer.pop(), Some(2));
+    assert_eq!(buffer.pop(), Some(3));
+    assert_eq!(buffer.pop(), None);
+}
+
+#[test]
+fn test_ring_is_wrapped() {
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    assert!(!buffer.is_wrapped());
+    buffer.write_slice(b"abcd");
+    assert!(!buffer.is_wrapped());
+    unsafe {
+        buffer.consume(2);
+    }
+    assert!(!buffer.is_wrapped());
+
+    buffer.write_slice(b"e");
+    assert!(buffer.is_wrapped());
+    assert!(!buffer.as_slices().1.is_empty());
+
+    unsafe {
+        buffer.consume(2);
+    }
+    assert!(!buffer.is_wrapped());
+    assert_eq!(buffer.as_slices(), (&b"e"[..], &b""[..]));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_write_to() {
+    struct Limited(Vec<u8>, usize);
+
+    impl std::io::Write for Limited {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let size = core::cmp::min(buf.len(), self.1 - self.0.len());
+            self.0.extend_from_slice(&buf[..size]);
+            Ok(size)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    buffer.write_slice(b"xxxxxxab");
+    unsafe {
+        buffer.consume(6);
+    }
+    buffer.write_slice(b"cde");
+    assert!(buffer.is_wrapped());
+
+    let mut sink = Vec::new();
+    assert_eq!(buffer.write_to(&mut sink).expect("to write"), 5);
+    assert_eq!(sink, b"abcde");
+    assert!(buffer.is_empty());
+
+    //partial writes consume only accepted bytes
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    buffer.write_slice(b"abcdef");
+    let mut sink = Limited(Vec::new(), 4);
+    assert_eq!(buffer.write_to(&mut sink).expect("to write"), 4);
+    assert_eq!(sink.0, b"abcd");
+    assert_eq!(buffer.as_slice(), b"ef");
+
+    let mut buffer = baffa::adapter::ChunkLimit::new(buffer, 1);
+    let mut sink = Vec::new();
+    assert_eq!(buffer.write_to(&mut sink).expect("to write"), 2);
+    assert_eq!(sink, b"ef");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_write_to_over_reported() {
+    struct OverReport(usize);
+
+    impl std::io::Write for OverReport {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0 += buf.len();
+            Ok(1000)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut buffer = StaticBuffer::<u16>::new().into_circular();
+    buffer.write_slice(b"xa");
+    unsafe {
+        buffer.consume(1);
+    }
+    buffer.write_slice(b"b");
+    assert!(buffer.is_wrapped());
+    let mut sink = OverReport(0);
+    assert_eq!(buffer.write_to(&mut sink).expect("to write"), 2);
+    assert_eq!(sink.0, 2);
+    assert!(buffer.is_empty());
+
+    let mut buffer = StaticBuffer::<u32>::new();
+    buffer.write_slice(b"abc");
+    let mut sink = OverReport(0);
+    assert_eq!(buffer.write_to(&mut sink).expect("to write"), 3);
+    assert_eq!(buffer.len(), 0);
+
+    let mut storage = [mem::MaybeUninit::<u8>::uninit(); 3];
+    let mut buffer = baffa::stack::BufferRef::new(&mut storage);
+    buffer.write_slice(b"abc");
+    let mut sink = OverReport(0);
+    assert_eq!(buffer.write_to(&mut sink).expect("to write"), 3);
+    assert_eq!(buffer.len(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_write_to_errors() {
+    //fails on every second call, alternating between interruption and error
+    struct Flaky {
+        out: Vec<u8>,
+        calls: usize,
+        limit: usize,
+    }
+
+    impl std::io::Write for Flaky {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.calls += 1;
+            match self.calls % 4 {
+                1 => {
+                    let size = core::cmp::min(buf.len(), self.limit);
+                    self.out.extend_from_slice(&buf[..size]);
+                    Ok(size)
+                },
+                2 => Err(std::io::ErrorKind::Interrupted.into()),
+                3 => {
+                    let size = core::cmp::min(buf.len(), self.limit);
+                    self.out.extend_from_slice(&buf[..size]);
+                    Ok(size)
+                },
+                _ => Err(std::io::ErrorKind::Other.into()),
+            }
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    buffer.write_slice(b"abcdefgh");
+    let mut sink = Flaky { out: Vec::new(), calls: 0, limit: 3 };
+    //interruption is retried, while error after written bytes is reported as partial write
+    assert_eq!(buffer.write_to(&mut sink).expect("to write"), 6);
+    assert_eq!(sink.out, b"abcdef");
+    assert_eq!(buffer.as_slice(), b"gh");
+    //error without written bytes is returned
+    sink.calls = 3;
+    assert!(buffer.write_to(&mut sink).is_err());
+    assert_eq!(buffer.as_slice(), b"gh");
+
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    buffer.write_slice(b"xxxxxxab");
+    unsafe {
+        buffer.consume(6);
+    }
+    buffer.write_slice(b"cdefgh");
+    let mut sink = Flaky { out: Vec::new(), calls: 0, limit: 3 };
+    assert_eq!(buffer.write_to(&mut sink).expect("to write"), 5);
+    assert_eq!(sink.out, b"abcde");
+    assert_eq!(buffer.as_slices(), (&b"fgh"[..], &b""[..]));
+
+    //contiguous bytes are written with single call
+    let mut storage = [mem::MaybeUninit::<u8>::uninit(); 100];
+    let mut buffer = baffa::stack::BufferRef::new(&mut storage);
+    buffer.write_slice(&[1; 100]);
+    let mut sink = Flaky { out: Vec::new(), calls: 0, limit: 100 };
+    assert_eq!(buffer.write_to(&mut sink).expect("to write"), 100);
+    assert_eq!(sink.calls, 1);
+    assert_eq!(buffer.len(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_read_from() {
+    let mut src = &b"abcdefghij"[..];
+
+    let mut buffer = StaticBuffer::<u64>::new();
+    assert_eq!(buffer.read_from(&mut src).expect("to read"), 8);
+    assert_eq!(buffer.as_slice(), b"abcdefgh");
+    assert_eq!(buffer.read_from(&mut src).expect("to read"), 0);
+    assert_eq!(src, b"ij");
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(b"xxa");
+    unsafe {
+        buffer.consume(2);
+    }
+    let mut src = &b"bcdef"[..];
+    assert_eq!(buffer.read_from(&mut src).expect("to read"), 1);
+    assert_eq!(buffer.read_from(&mut src).expect("to read"), 2);
+    assert_eq!(buffer.read_from(&mut src).expect("to read"), 0);
+    assert_eq!(buffer.slice_to_array::<4>(0), Some(*b"abcd"));
+
+    let mut storage = [mem::MaybeUninit::<u8>::uninit(); 3];
+    let mut buffer = baffa::stack::BufferRef::new(&mut storage);
+    let mut src = &b"abcd"[..];
+    assert_eq!(buffer.read_from(&mut src).expect("to read"), 3);
+    assert_eq!(buffer.as_slice(), b"abc");
+    assert_eq!(buffer.read_from(&mut src).expect("to read"), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_read_from_contiguous() {
+    let data = [7u8; 200];
+
+    //whole spare capacity is filled with single call
+    let mut storage = [mem::MaybeUninit::<u8>::uninit(); 150];
+    let mut buffer = baffa::stack::BufferRef::new(&mut storage);
+    let mut src = &data[..];
+    assert_eq!(buffer.read_from(&mut src).expect("to read"), 150);
+    assert_eq!(buffer.as_slice(), &data[..150]);
+
+    //spare bytes are initialized once, and stay so after consumption
+    buffer.truncate(10);
+    let mut src = &b"abc"[..];
+    assert_eq!(buffer.read_from(&mut src).expect("to read"), 3);
+    assert_eq!(&buffer.as_slice()[10..], b"abc");
+
+    let mut buffer = StaticBuffer::<[u8; 150]>::new();
+    let mut src = &data[..];
+    assert_eq!(buffer.read_from(&mut src).expect("to read"), 150);
+    unsafe {
+        buffer.consume(140);
+    }
+    assert_eq!(buffer.read_from(&mut src).expect("to read"), 50);
+    assert_eq!(buffer.len(), 60);
+
+    //writable slice may be de-initialized by user
+    buffer.truncate(0);
+    for byte in buffer.as_write_slice() {
+        *byte = mem::MaybeUninit::uninit();
+    }
+    let mut src = &b"abc"[..];
+    assert_eq!(buffer.read_from(&mut src).expect("to read"), 3);
+    assert_eq!(buffer.as_slice(), b"abc");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_read_from_over_reported() {
+    struct OverReport;
+
+    impl std::io::Read for OverReport {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            for byte in buf.iter_mut() {
+                *byte = b'a';
+            }
+            Ok(1000)
+        }
+    }
+
+    let mut buffer = StaticBuffer::<u32>::new();
+    assert_eq!(buffer.read_from(&mut OverReport).expect("to read"), 4);
+    assert_eq!(buffer.len(), 4);
+    assert_eq!(buffer.as_slice(), b"aaaa");
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(b"x");
+    assert_eq!(buffer.read_from(&mut OverReport).expect("to read"), 3);
+    assert_eq!(buffer.len(), 4);
+    assert_eq!(buffer.slice_to_array::<4>(0), Some(*b"xaaa"));
+
+    let mut storage = [mem::MaybeUninit::<u8>::uninit(); 3];
+    let mut buffer = baffa::stack::BufferRef::new(&mut storage);
+    assert_eq!(buffer.read_from(&mut OverReport).expect("to read"), 3);
+    assert_eq!(buffer.as_slice(), b"aaa");
+}
+
+#[test]
+fn test_eq_ignore_ascii_case() {
+    let mut buffer = StaticBuffer::<[u8; 16]>::new();
+    buffer.write_slice(b"Content-Length");
+    assert!(buffer.eq_ignore_ascii_case(b"content-length"));
+    assert!(buffer.eq_ignore_ascii_case(b"CONTENT-LENGTH"));
+    assert!(!buffer.eq_ignore_ascii_case(b"content-type"));
+    assert!(!buffer.eq_ignore_ascii_case(b"content-lengt"));
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(b"xxAb");
+    unsafe {
+        buffer.consume(2);
+    }
+    buffer.write_slice(b"C");
+    assert!(buffer.eq_ignore_ascii_case(b"aBc"));
+    assert!(!buffer.eq_ignore_ascii_case(b"cab"));
+}
+
+#[test]
+fn test_reset_full() {
+    let mut buffer = StaticBuffer::<[u8; 8]>::zeroed();
+    assert_eq!(buffer.len(), 0);
+    unsafe {
+        buffer.reset_full();
+    }
+    assert_eq!(buffer.len(), 8);
+    assert_eq!(buffer.remaining(), 0);
+    assert_eq!(buffer.as_slice(), [0u8; 8]);
+
+    buffer.as_mut_slice()[2..4].copy_from_slice(b"ab");
+    buffer.consume_all();
+    assert_eq!(buffer.len(), 0);
+    unsafe {
+        buffer.reset_full();
+    }
+    assert_eq!(buffer.as_slice(), b"\0\0ab\0\0\0\0");
+}
+
+#[test]
+fn test_bitmap() {
+    let bits = [true, false, true, true, false, false, false, true, true, true];
+
+    let mut buffer = StaticBuffer::<[u8; 4]>::new();
+    assert_eq!(buffer.write_bitmap(bits.iter().cloned()), 2);
+    assert_eq!(buffer.as_slice(), [0b1011_0001, 0b1100_0000]);
+
+    let bitmap = buffer.read_bitmap(bits.len());
+    assert_eq!(bitmap.size_hint(), (10, Some(10)));
+    let mut result = [false; 10];
+    for (idx, bit) in bitmap.enumerate() {
+        result[idx] = bit;
+    }
+    assert_eq!(result, bits);
+    assert_eq!(buffer.len(), 0);
+
+    //stops at byte that cannot fit
+    let mut buffer = StaticBuffer::<u8>::new();
+    assert_eq!(buffer.write_bitmap(bits.iter().cloned()), 1);
+    assert_eq!(buffer.read_bitmap(16).count(), 8);
+    assert_eq!(buffer.write_bitmap(core::iter::empty()), 0);
+}
+
+#[test]
+fn test_leading_trailing_zeros() {
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    assert_eq!(buffer.leading_zeros(), 0);
+    assert_eq!(buffer.trailing_zeros(), 0);
+
+    buffer.write_slice(&[0, 0, 5, 0]);
+    assert_eq!(buffer.leading_zeros(), 2);
+    assert_eq!(buffer.trailing_zeros(), 1);
+
+    buffer.truncate(2);
+    assert_eq!(buffer.leading_zeros(), 2);
+    assert_eq!(buffer.trailing_zeros(), 2);
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(&[1, 1, 0]);
+    unsafe {
+        buffer.consume(2);
+    }
+    buffer.write_slice(&[7, 0, 0]);
+    assert_eq!(buffer.leading_zeros(), 1);
+    assert_eq!(buffer.trailing_zeros(), 2);
+}
+
+#[test]
+fn test_xor_checksum() {
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    assert_eq!(buffer.xor_checksum(), 0);
+
+    //NMEA sentence "$GPGLL,5300.97914,N,00259.98174,E,125926,A*28" body
+    let mut nmea = StaticBuffer::<[u8; 64]>::new();
+    nmea.write_slice(b"GPGLL,5300.97914,N,00259.98174,E,125926,A");
+    assert_eq!(nmea.xor_checksum(), 0x28);
+    assert!(nmea.verify_xor(0x28));
+
+    buffer.write_slice(&[0x01, 0x02, 0x04, 0xf0]);
+    assert_eq!(buffer.xor_checksum(), 0xf7);
+    assert!(!buffer.verify_xor(0));
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(&[0xff, 0xff, 0x01]);
+    unsafe {
+        buffer.consume(2);
+    }
+    buffer.write_slice(&[0x02, 0x04]);
+    assert_eq!(buffer.xor_checksum(), 0x07);
+}
+
+#[test]
+fn test_ones_complement_sum() {
+    let mut buffer = StaticBuffer::<[u8; 16]>::new();
+    assert_eq!(buffer.ones_complement_sum(), 0);
+
+    //RFC 1071 example
+    buffer.write_slice(&[0x00, 0x01, 0xf2, 0x03, 0xf4, 0xf5, 0xf6, 0xf7]);
+    assert_eq!(buffer.ones_complement_sum(), 0xddf2);
+    assert_eq!(!buffer.ones_complement_sum(), 0x220d);
+
+    //odd length is padded with zero
+    buffer.consume_all();
+    buffer.write_slice(&[0x12, 0x34, 0x56]);
+    assert_eq!(buffer.ones_complement_sum(), 0x1234 + 0x5600);
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(&[0xff, 0xff, 0xff]);
+    unsafe {
+        buffer.consume(2);
+    }
+    buffer.write_slice(&[0xff, 0x00, 0x02]);
+    assert_eq!(buffer.ones_complement_sum(), 0x0002);
+}
+
+#[test]
+fn test_as_typed_slice() {
+    let mut buffer = StaticBuffer::<[u16; 4]>::new();
+    for value in [1u16, 2, 3].iter() {
+        buffer.write_value(value);
+    }
+
+    unsafe {
+        assert_eq!(buffer.as_typed_slice::<u16>(), Some(&[1u16, 2, 3][..]));
+        for value in buffer.as_typed_slice_mut::<u16>().expect("to be aligned") {
+            *value *= 2;
+        }
+        assert_eq!(buffer.as_typed_slice::<u16>(), Some(&[2u16, 4, 6][..]));
+        assert_eq!(buffer.as_typed_slice::<()>(), None);
+    }
+
+    //length is not multiple of size
+    buffer.write_slice(&[0]);
+    unsafe {
+        assert_eq!(buffer.as_typed_slice::<u16>(), None);
+        assert_eq!(buffer.as_typed_slice_mut::<u16>(), None);
+    }
+}
+
+#[test]
+fn test_ring_write_value_checked() {
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    assert!(buffer.write_value_checked(&u64::MAX).is_err());
+    assert!(buffer.is_empty());
+
+    assert!(buffer.write_value_checked(&0x01020304u32).is_ok());
+    assert_eq!(buffer.len(), 4);
+    assert!(buffer.write_value_checked(&0x0506u16).is_ok());
+    assert_eq!(buffer.len(), 4);
+    assert_eq!(buffer.as_slices(), (&0x01020304u32.to_ne_bytes()[2..], &0x0506u16.to_ne_bytes()[..]));
+}
+
+#[test]
+fn test_ring_from_iter_overwriting() {
+    let buffer = baffa::RingBuffer::<u64>::from_iter_overwriting(0..20u8);
+    assert_eq!(buffer.len(), 8);
+    let mut out = [0u8; 8];
+    for (idx, byte) in out.iter_mut().enumerate() {
+        *byte = buffer[idx];
+    }
+    assert_eq!(out, [12, 13, 14, 15, 16, 17, 18, 19]);
+
+    let buffer = baffa::RingBuffer::<u64>::from_iter_overwriting(b"abc".iter().cloned());
+    assert_eq!(buffer.as_slices(), (&b"abc"[..], &b""[..]));
+
+    let buffer = baffa::RingBuffer::<u64>::from_iter_overwriting((0..100_000u32).map(|idx| idx as u8));
+    assert_eq!(buffer.len(), 8);
+    assert_eq!(buffer[7], 99_999u32 as u8);
+}
+
+#[test]
+fn test_ring_free() {
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    assert_eq!(buffer.free(), 8);
+
+    buffer.write_slice(b"abc");
+    assert_eq!(buffer.free(), 5);
+    assert_eq!(buffer.remaining(), 8);
+
+    unsafe {
+        buffer.consume(1);
+    }
+    assert_eq!(buffer.free(), 6);
+
+    buffer.write_slice(b"0123456789");
+    assert_eq!(buffer.free(), 0);
+    assert_eq!(buffer.remaining(), 8);
+}
+
+#[test]
+fn test_read_until_seq() {
+    let mut out = [0u8; 16];
+
+    let mut buffer = StaticBuffer::<[u8; 32]>::new();
+    buffer.write_slice(b"Host: a\r\n\r");
+    assert_eq!(buffer.read_until_seq(&mut out, b"\r\n\r\n"), None);
+    assert_eq!(buffer.len(), 10);
+
+    //rest of sentinel arrives with next read
+    buffer.write_slice(b"\nbody");
+    assert_eq!(buffer.read_until_seq(&mut out, b"\r\n\r\n"), Some(&b"Host: a"[..]));
+    assert_eq!(buffer.as_slice(), b"body");
+
+    //doesn't fit
+    buffer.write_slice(b"\r\n");
+    assert_eq!(buffer.read_until_seq(&mut out[..5], b"\r\n"), None);
+    assert_eq!(buffer.read_until_seq(&mut out[..6], b"\r\n"), Some(&b"body"[..]));
+    assert_eq!(buffer.len(), 0);
+    assert_eq!(buffer.read_until_seq(&mut out, b"\r\n"), None);
+
+    let mut buffer = StaticBuffer::<u64>::new().into_circular();
+    buffer.write_slice(b"xxxxxab");
+    unsafe {
+        buffer.consume(5);
+    }
+    buffer.write_slice(b"c;;d");
+    assert_eq!(buffer.read_until_seq(&mut out, b";;"), Some(&b"abc"[..]));
+    assert_eq!(buffer.len(), 1);
+}
+
+#[test]
+fn test_copy_to_array() {
+    let mut buffer = StaticBuffer::<[u8; 8]>::new();
+    assert_eq!(buffer.copy_to_array::<0>(), Some([]));
+    assert_eq!(buffer.copy_to_array::<1>(), None);
+
+    buffer.write_slice(b"abcde");
+    assert_eq!(buffer.copy_to_array::<4>(), Some(*b"abcd"));
+    assert_eq!(buffer.copy_to_array::<6>(), None);
+    assert_eq!(buffer.len(), 5);
+
+    let mut buffer = StaticBuffer::<u32>::new().into_circular();
+    buffer.write_slice(b"xxab");
+    unsafe {
+        buffer.consume(2);
+    }
+    buffer.write_slice(b"cd");
+    assert!(buffer.is_wrapped());
+    assert_eq!(buffer.copy_to_array::<4>(), Some(*b"abcd"));
+    assert_eq!(buffer.len(), 4);
+}
+
+#[test]
+fn test_finalize_xor() {
+    let mut buffer = StaticBuffer::<[u8; 4]>::new();
+    buffer.write_slice(&[0x10, 0x22, 0x03]);
+    assert!(buffer.finalize_xor());
+    assert_eq!(buffer.as_slice(), [0x10, 0x22, 0x03, 0x31]);
+    //whole frame, including checksum, XORs to zero
+    assert_eq!(buffer.xor_checksum(), 0);
+
+    assert!(!buffer.finalize_xor());
+    assert_eq!(buffer.len(), 4);
+
+    let mut buffer = StaticBuffer::<u8>::new();
+    assert!(buffer.finalize_xor());
+    assert_eq!(buffer.as_slice(), [0]);
+}
+
+#[test]
+fn test_write_int_endian() {
+    let mut buffer = StaticBuffer::<[u8; 32]>::new();
+    assert_eq!(buffer.write_u16_le(0x0102), 2);
+    assert_eq!(buffer.write_u16_be(0x0102), 2);
+    assert_eq!(buffer.write_u32_le(0x01020304), 4);
+    assert_eq!(buffer.write_u32_be(0x01020304), 4);
+    assert_eq!(buffer.as_slice(), [2, 1, 1, 2, 4, 3, 2, 1, 1, 2, 3, 4]);
+
+    assert_eq!(buffer.take_array::<2>().map(u16::from_le_bytes), Some(0x0102));
+    assert_eq!(buffer.take_array::<2>().map(u16::from_be_bytes), Some(0x0102));
+    assert_eq!(buffer.take_array::<4>().map(u32::from_le_bytes), Some(0x01020304));
+    assert_eq!(buffer.take_array::<4>().map(u32::from_be_bytes), Some(0x01020304));
+
+    assert_eq!(buffer.write_i16_le(-2), 2);
+    assert_eq!(buffer.write_i16_be(-2), 2);
+    assert_eq!(buffer.write_i32_be(-1), 4);
+    assert_eq!(buffer.write_u64_be(0x0102030405060708), 8);
+    assert_eq!(buffer.write_i64_le(i64::MIN), 8);
+    assert_eq!(buffer.take_array::<2>(), Some([0xfe, 0xff]));
+    assert_eq!(buffer.take_array::<2>(), Some([0xff, 0xfe]));
+    assert_eq!(buffer.take_array::<4>().map(i32::from_be_bytes), Some(-1));
+    assert_eq!(buffer.take_array::<8>(), Some([1, 2, 3, 4, 5, 6, 7, 8]));
+    assert_eq!(buffer.take_array::<8>().map(i64::from_le_bytes), Some(i64::MIN));
+
+    //does nothing if it cannot fit
+    let mut buffer = StaticBuffer::<u32>::new();
+    assert_eq!(buffer.write_u64_le(1), 0);
+    assert_eq!(buffer.write_i32_le(-1), 4);
+    assert_eq!(buffer.write_u16_be(1), 0);
+    assert_eq!(buffer.as_slice(), [0xff; 4]);
+}